@@ -0,0 +1,89 @@
+use crate::{FromLiteralSigned, FromLiteralStr, FromLiteralUnsigned, TypeStr};
+
+/// Build your datatype from an unsigned integer literal in the initializer of a `const` or `static` item.
+///
+/// Trait methods cannot be `const fn`s on stable Rust, so [into_self](FromLiteralUnsigned::into_self) cannot be called there.
+/// Instead, the [macro@overloaded_literals] macro turns an integer literal in a `const` or `static` item
+/// inside an annotated function, like the `10` in
+/// ```compile_only
+/// const MAX: NonZeroU8 = 10;
+/// ```
+/// into the associated constant
+/// ```compile_only
+/// <NonZeroU8 as ConstFromLiteralUnsigned<10>>::VALUE
+/// ```
+///
+/// This trait has [FromLiteralUnsigned] as a supertrait, so the literal is checked by the very same `VALID_LITERAL`.
+/// It is implemented for the same types from the standard library as [FromLiteralUnsigned] is.
+///
+/// ```rust
+/// use overloaded_literals::{overloaded_literals, ConstFromLiteralUnsigned, FromLiteralUnsigned};
+///
+/// /// A percentage (`0` up to and including `100`).
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// pub struct Percent(u8);
+///
+/// impl<const LIT: u128> FromLiteralUnsigned<LIT> for Percent {
+///     const VALID_LITERAL: u128 = {
+///         assert!(LIT <= 100, "Percent cannot be larger than 100");
+///         LIT
+///     };
+///
+///     fn into_self() -> Self {
+///         <Self as ConstFromLiteralUnsigned<LIT>>::VALUE
+///     }
+/// }
+///
+/// impl<const LIT: u128> ConstFromLiteralUnsigned<LIT> for Percent {
+///     const VALUE: Self = Percent(<Self as FromLiteralUnsigned<LIT>>::VALID_LITERAL as u8);
+/// }
+///
+/// #[overloaded_literals]
+/// fn example() {
+///     const HALF: Percent = 50;
+///     static FULL: Percent = 100;
+///     let quarter: Percent = 25;
+///     assert_eq!((HALF.0, FULL.0, quarter.0), (50, 100, 25));
+/// }
+/// example()
+/// ```
+pub trait ConstFromLiteralUnsigned<const LIT: u128>: FromLiteralUnsigned<LIT> {
+    /// The value built from the literal, at compile-time.
+    ///
+    /// Build it from [VALID_LITERAL](FromLiteralUnsigned::VALID_LITERAL) (rather than from `LIT` directly),
+    /// so the literal is checked.
+    const VALUE: Self;
+}
+
+/// Build your datatype from a signed integer literal (-1, -2, -3, ...) in the initializer of a `const` or `static` item.
+///
+/// The counterpart of [ConstFromLiteralUnsigned] for [FromLiteralSigned]; see there for how it is used.
+pub trait ConstFromLiteralSigned<const LIT: i128>: FromLiteralSigned<LIT> {
+    /// The value built from the literal, at compile-time.
+    ///
+    /// Build it from [VALID_LITERAL](FromLiteralSigned::VALID_LITERAL) (rather than from `LIT` directly),
+    /// so the literal is checked.
+    const VALUE: Self;
+}
+
+/// Build your datatype from a string literal in the initializer of a `const` or `static` item.
+///
+/// The counterpart of [ConstFromLiteralUnsigned] for [FromLiteralStr]; see there for how it is used.
+///
+/// ```rust
+/// use overloaded_literals::{overloaded_literals, examples::SqlIdent};
+///
+/// #[overloaded_literals]
+/// fn example() {
+///     const TABLE: SqlIdent = "users";
+///     assert_eq!(TABLE.as_str(), "users");
+/// }
+/// example()
+/// ```
+pub trait ConstFromLiteralStr<TStr: TypeStr>: FromLiteralStr<TStr> {
+    /// The value built from the literal, at compile-time.
+    ///
+    /// Build it from [VALID_LITERAL](FromLiteralStr::VALID_LITERAL) (rather than from `TStr::STR` directly),
+    /// so the literal is checked.
+    const VALUE: Self;
+}
@@ -0,0 +1,73 @@
+/// Validates an integer literal using the [FromLiteralUnsigned](crate::FromLiteralUnsigned) implementation of a type,
+/// and evaluates to the literal as a constant, for use as a const generic argument.
+///
+/// `const_literal!(Type, 4096)` evaluates to `<Type as FromLiteralUnsigned<4096>>::VALID_LITERAL` (a `u128`).
+/// Add `as` and the type of the const generic parameter to cast it: `const_literal!(Type, 4096 as usize)`.
+/// Wrapped in braces, it can be used as a const generic argument, like `SizedBuffer::<{ const_literal!(PageSize, 4096 as usize) }>`.
+/// So `Type` performs the validation, while the value ends up as a const generic rather than as a runtime value built by `into_self()`.
+///
+/// This works because `VALID_LITERAL` is evaluated at compile time anyway.
+/// It does impose the usual requirements of const evaluation in generic position:
+/// - The argument has to be an integer literal; it cannot be a variable.
+/// - `Type` cannot depend on generic parameters of the surrounding item
+///   (using those in a const generic expression requires the unstable `generic_const_exprs` feature).
+/// - Since const generic arguments are evaluated while type checking, an invalid literal is already rejected by `cargo check`.
+///
+/// Most of the time, you will wrap it in a macro of your own for your API:
+///
+/// ```rust
+/// use overloaded_literals::{const_literal, FromLiteralUnsigned};
+///
+/// /// A buffer size, which has to be a nonzero power of two.
+/// pub struct BufferSize;
+///
+/// impl<const LIT: u128> FromLiteralUnsigned<LIT> for BufferSize {
+///     const VALID_LITERAL: u128 = {
+///         assert!(LIT.is_power_of_two(), "buffer size has to be a power of two");
+///         LIT
+///     };
+///
+///     fn into_self() -> Self {
+///         BufferSize
+///     }
+/// }
+///
+/// pub struct SizedBuffer<const SIZE: usize>([u8; SIZE]);
+///
+/// impl<const SIZE: usize> SizedBuffer<SIZE> {
+///     pub fn new() -> Self {
+///         SizedBuffer([0; SIZE])
+///     }
+///
+///     pub fn len(&self) -> usize {
+///         SIZE
+///     }
+/// }
+///
+/// macro_rules! sized_buffer {
+///     ($size:literal) => {
+///         SizedBuffer::<{ const_literal!(BufferSize, $size as usize) }>::new()
+///     };
+/// }
+///
+/// let buffer = sized_buffer!(4096);
+/// assert_eq!(buffer.len(), 4096);
+/// assert_eq!(const_literal!(BufferSize, 64), 64u128);
+/// ```
+///
+/// ```compile_fail
+/// use overloaded_literals::{const_literal, examples::Capacity};
+///
+/// struct SizedBuffer<const SIZE: usize>;
+///
+/// let buffer = SizedBuffer::<{ const_literal!(Capacity<256>, 257 as usize) }>;
+/// ```
+#[macro_export]
+macro_rules! const_literal {
+    ($type:ty, $lit:literal) => {
+        <$type as $crate::FromLiteralUnsigned<$lit>>::VALID_LITERAL
+    };
+    ($type:ty, $lit:literal as $target:ty) => {
+        <$type as $crate::FromLiteralUnsigned<$lit>>::VALID_LITERAL as $target
+    };
+}
@@ -0,0 +1,46 @@
+use core::fmt;
+
+/// The reason a value which is only known at runtime was rejected by [try_from_value](crate::TryFromLiteralValue::try_from_value).
+///
+/// ```rust
+/// use overloaded_literals::{LiteralError, TryFromLiteralValue};
+/// use std::num::NonZeroU8;
+///
+/// let parse = |value: u128| NonZeroU8::try_from_value(value);
+/// assert_eq!(parse(42).map(NonZeroU8::get), Ok(42));
+/// assert_eq!(parse(0), Err(LiteralError::Zero));
+/// assert_eq!(parse(256), Err(LiteralError::OutOfRange));
+/// assert_eq!(LiteralError::Zero.to_string(), "integer was 0");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LiteralError {
+    /// An integer outside of the range the type accepts.
+    OutOfRange,
+    /// Zero, for a type which cannot be zero (like `NonZeroU8`).
+    Zero,
+    /// A string which the type does not accept.
+    InvalidStr,
+    /// A byte string which the type does not accept.
+    InvalidByteStr,
+    /// A character which the type does not accept.
+    InvalidChar,
+    /// A byte which the type does not accept.
+    InvalidByte,
+}
+
+impl fmt::Display for LiteralError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            LiteralError::OutOfRange => "integer out of range",
+            LiteralError::Zero => "integer was 0",
+            LiteralError::InvalidStr => "invalid string",
+            LiteralError::InvalidByteStr => "invalid byte string",
+            LiteralError::InvalidChar => "invalid character",
+            LiteralError::InvalidByte => "invalid byte",
+        };
+        f.write_str(message)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LiteralError {}
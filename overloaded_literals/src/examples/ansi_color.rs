@@ -0,0 +1,75 @@
+crate::literal_map! {
+    /// One of the sixteen standard ANSI terminal colors, named like `"red"` or `"bright-red"`.
+    ///
+    /// Requires the given string literal to be one of:
+    /// `black`, `red`, `green`, `yellow`, `blue`, `magenta`, `cyan` and `white`,
+    /// or one of these prefixed with `bright-`.
+    ///
+    /// Built with [literal_map!](crate::literal_map), mapping each name to its foreground SGR code.
+    /// `Display`-formatting it writes the escape sequence which switches the terminal's foreground to the color.
+    ///
+    /// ```rust
+    /// # use overloaded_literals::overloaded_literals;
+    /// # use overloaded_literals::examples::AnsiColor;
+    ///
+    /// #[overloaded_literals]
+    /// pub fn correct() {
+    ///    let error: AnsiColor = "bright-red";
+    ///    assert_eq!(error.sgr_code(), 91);
+    ///    assert_eq!(error.background_sgr_code(), 101);
+    ///    assert_eq!(error.to_string(), "\x1b[91m");
+    ///
+    ///    let info: AnsiColor = "blue";
+    ///    assert_eq!(info.sgr_code(), 34);
+    /// }
+    /// correct()
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use overloaded_literals::overloaded_literals;
+    /// # use overloaded_literals::examples::AnsiColor;
+    ///
+    /// #[overloaded_literals]
+    /// pub fn oops_unknown_color() {
+    ///    let color: AnsiColor = "orange";
+    /// }
+    /// oops_unknown_color()
+    /// ```
+    pub struct AnsiColor: u8 {
+        "black" => 30,
+        "red" => 31,
+        "green" => 32,
+        "yellow" => 33,
+        "blue" => 34,
+        "magenta" => 35,
+        "cyan" => 36,
+        "white" => 37,
+        "bright-black" => 90,
+        "bright-red" => 91,
+        "bright-green" => 92,
+        "bright-yellow" => 93,
+        "bright-blue" => 94,
+        "bright-magenta" => 95,
+        "bright-cyan" => 96,
+        "bright-white" => 97,
+    }
+}
+
+impl AnsiColor {
+    /// The SGR code which sets the foreground to this color, like `31` for `"red"`.
+    pub fn sgr_code(&self) -> u8 {
+        self.value()
+    }
+
+    /// The SGR code which sets the background to this color, like `41` for `"red"`.
+    pub fn background_sgr_code(&self) -> u8 {
+        self.value() + 10
+    }
+}
+
+/// Writes the escape sequence which sets the foreground to this color, like `"\x1b[31m"` for `"red"`.
+impl core::fmt::Display for AnsiColor {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "\x1b[{}m", self.sgr_code())
+    }
+}
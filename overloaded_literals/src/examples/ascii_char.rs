@@ -0,0 +1,77 @@
+use crate::{FromLiteralChar, LiteralError, TryFromLiteralValue};
+
+/// A single ASCII character (`'\0'` up to and including `'\x7f'`).
+///
+/// Requires the given char literal to be ASCII.
+///
+/// ```rust
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::AsciiChar;
+///
+/// #[overloaded_literals]
+/// pub fn correct() {
+///    let letter: AsciiChar = 'a';
+///    let newline: AsciiChar = '\n';
+///    assert_eq!(letter.as_char(), 'a');
+///    assert_eq!(letter.as_byte(), b'a');
+///    assert_eq!(newline.as_byte(), 10);
+/// }
+/// correct()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::AsciiChar;
+///
+/// #[overloaded_literals]
+/// pub fn oops_not_ascii() {
+///    let letter: AsciiChar = 'λ';
+/// }
+/// oops_not_ascii()
+/// ```
+///
+/// A char which is only known at runtime goes through the same check with [try_from_value](TryFromLiteralValue::try_from_value):
+///
+/// ```rust
+/// # use overloaded_literals::examples::AsciiChar;
+/// use overloaded_literals::{LiteralError, TryFromLiteralValue};
+///
+/// let first = "zebra".chars().next().unwrap();
+/// assert_eq!(AsciiChar::try_from_value(first).map(|letter| letter.as_byte()), Ok(b'z'));
+/// assert_eq!(AsciiChar::try_from_value('λ'), Err(LiteralError::InvalidChar));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AsciiChar(u8);
+
+impl AsciiChar {
+    pub fn as_char(&self) -> char {
+        self.0 as char
+    }
+
+    pub fn as_byte(&self) -> u8 {
+        self.0
+    }
+}
+
+impl<const LIT: char> FromLiteralChar<LIT> for AsciiChar {
+    const VALID_LITERAL: char = {
+        assert!(LIT.is_ascii(), "AsciiChar has to be an ASCII character");
+        LIT
+    };
+
+    #[inline]
+    fn into_self() -> Self {
+        AsciiChar(<Self as FromLiteralChar<LIT>>::VALID_LITERAL as u8)
+    }
+}
+
+impl TryFromLiteralValue<char> for AsciiChar {
+    #[inline]
+    fn try_from_value(value: char) -> Result<Self, LiteralError> {
+        if value.is_ascii() {
+            Ok(AsciiChar(value as u8))
+        } else {
+            Err(LiteralError::InvalidChar)
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{FromLiteralStr, TypeStr};
+
+/// A Base58-encoded address, like a legacy Bitcoin address `"1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2"`.
+///
+/// Requires the given string literal to:
+/// - be between 26 and 35 characters long (inclusive)
+/// - only contain characters from the Base58 alphabet:
+///   ASCII letters and digits, except for the easily confused `0` (zero), `O` (capital o), `I` (capital i) and `l` (lowercase L)
+///
+/// The checksum contained in many address formats is not checked.
+///
+/// ```rust
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::Base58Addr;
+///
+/// #[overloaded_literals]
+/// pub fn correct() {
+///    let addr: Base58Addr = "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2";
+///    assert_eq!(addr.as_str(), "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2");
+///    let addr: Base58Addr = "3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy";
+///    assert_eq!(addr.as_str().len(), 34);
+/// }
+/// correct()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::Base58Addr;
+///
+/// #[overloaded_literals]
+/// pub fn oops_zero() {
+///    let addr: Base58Addr = "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN0";
+/// }
+/// oops_zero()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::Base58Addr;
+///
+/// #[overloaded_literals]
+/// pub fn oops_lowercase_l() {
+///    let addr: Base58Addr = "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVNl";
+/// }
+/// oops_lowercase_l()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::Base58Addr;
+///
+/// #[overloaded_literals]
+/// pub fn oops_too_short() {
+///    let addr: Base58Addr = "1BvBMSEY";
+/// }
+/// oops_too_short()
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Base58Addr(&'static str);
+
+impl Base58Addr {
+    pub fn as_str(&self) -> &'static str {
+        self.0
+    }
+}
+
+/// The Base58 alphabet, as used by Bitcoin.
+const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+const fn is_base58_byte(byte: u8) -> bool {
+    let mut index = 0;
+    while index < ALPHABET.len() {
+        if ALPHABET[index] == byte {
+            return true;
+        }
+        index += 1;
+    }
+    false
+}
+
+impl<Str: TypeStr> FromLiteralStr<Str> for Base58Addr {
+    const VALID_LITERAL: &'static str = {
+        let bytes = Str::STR.as_bytes();
+        assert!(
+            bytes.len() >= 26 && bytes.len() <= 35,
+            "Base58Addr has to be between 26 and 35 characters long"
+        );
+        let mut index = 0;
+        while index < bytes.len() {
+            assert!(
+                is_base58_byte(bytes[index]),
+                "Base58Addr can only contain characters of the Base58 alphabet (no `0`, `O`, `I` or `l`)"
+            );
+            index += 1;
+        }
+        Str::STR
+    };
+
+    #[inline]
+    fn into_self() -> Self {
+        Base58Addr(<Self as FromLiteralStr<Str>>::VALID_LITERAL)
+    }
+}
@@ -0,0 +1,136 @@
+use crate::FromLiteralUnsigned;
+
+macro_rules! byte_unit {
+    ($(#[$attr:meta])* $name:ident, $bytes_per_unit:expr) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name(u64);
+
+        impl $name {
+            /// The number of bytes in one unit.
+            pub const BYTES_PER_UNIT: u64 = $bytes_per_unit;
+
+            /// The total size in bytes.
+            pub fn bytes(&self) -> u64 {
+                self.0
+            }
+
+            /// The size in the unit it was written in.
+            pub fn get(&self) -> u64 {
+                self.0 / Self::BYTES_PER_UNIT
+            }
+        }
+
+        impl<const LIT: u128> FromLiteralUnsigned<LIT> for $name {
+            const VALID_LITERAL: u128 = {
+                assert!(
+                    LIT <= (u64::MAX / $name::BYTES_PER_UNIT) as u128,
+                    concat!(stringify!($name), " literal is too large: its number of bytes does not fit in a u64")
+                );
+                LIT
+            };
+
+            #[inline]
+            fn into_self() -> Self {
+                $name(<Self as FromLiteralUnsigned<LIT>>::VALID_LITERAL as u64 * $name::BYTES_PER_UNIT)
+            }
+        }
+    };
+}
+
+byte_unit!(
+    /// A size in kibibytes (1024 bytes), stored as a number of bytes.
+    ///
+    /// Requires the number of bytes (the given integer literal times 1024) to fit in a `u64`.
+    ///
+    /// ```rust
+    /// # use overloaded_literals::overloaded_literals;
+    /// # use overloaded_literals::examples::Kib;
+    ///
+    /// #[overloaded_literals]
+    /// pub fn correct() {
+    ///    let size: Kib = 4;
+    ///    assert_eq!(size.bytes(), 4096);
+    ///    assert_eq!(size.get(), 4);
+    /// }
+    /// correct()
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use overloaded_literals::overloaded_literals;
+    /// # use overloaded_literals::examples::Kib;
+    ///
+    /// #[overloaded_literals]
+    /// pub fn oops_overflow() {
+    ///    let size: Kib = 18014398509481984;
+    /// }
+    /// oops_overflow()
+    /// ```
+    Kib,
+    1024
+);
+
+byte_unit!(
+    /// A size in mebibytes (1024 * 1024 bytes), stored as a number of bytes.
+    ///
+    /// Requires the number of bytes (the given integer literal times 1024²) to fit in a `u64`.
+    ///
+    /// ```rust
+    /// # use overloaded_literals::overloaded_literals;
+    /// # use overloaded_literals::examples::Mib;
+    ///
+    /// #[overloaded_literals]
+    /// pub fn correct() {
+    ///    let size: Mib = 64;
+    ///    assert_eq!(size.bytes(), 64 * 1024 * 1024);
+    /// }
+    /// correct()
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use overloaded_literals::overloaded_literals;
+    /// # use overloaded_literals::examples::Mib;
+    ///
+    /// #[overloaded_literals]
+    /// pub fn oops_overflow() {
+    ///    let size: Mib = 17592186044416;
+    /// }
+    /// oops_overflow()
+    /// ```
+    Mib,
+    1024 * 1024
+);
+
+byte_unit!(
+    /// A size in gibibytes (1024 * 1024 * 1024 bytes), stored as a number of bytes.
+    ///
+    /// Requires the number of bytes (the given integer literal times 1024³) to fit in a `u64`.
+    ///
+    /// ```rust
+    /// # use overloaded_literals::overloaded_literals;
+    /// # use overloaded_literals::examples::Gib;
+    ///
+    /// #[overloaded_literals]
+    /// pub fn correct() {
+    ///    let size: Gib = 2;
+    ///    assert_eq!(size.bytes(), 2 * 1024 * 1024 * 1024);
+    ///
+    ///    let max: Gib = 17179869183;
+    ///    assert_eq!(max.get(), 17179869183);
+    /// }
+    /// correct()
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use overloaded_literals::overloaded_literals;
+    /// # use overloaded_literals::examples::Gib;
+    ///
+    /// #[overloaded_literals]
+    /// pub fn oops_overflow() {
+    ///    let size: Gib = 17179869184;
+    /// }
+    /// oops_overflow()
+    /// ```
+    Gib,
+    1024 * 1024 * 1024
+);
@@ -0,0 +1,69 @@
+use crate::FromLiteralUnsigned;
+
+/// The capacity of a fixed-capacity container, which is at least one and at most `MAX`.
+///
+/// Requires the given integer literal to be in the range `1..=MAX`,
+/// so both bounds are checked at compile time: the floor of one is fixed and the ceiling comes from the const generic parameter.
+///
+/// ```rust
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::Capacity;
+///
+/// #[overloaded_literals]
+/// pub fn correct() {
+///    let smallest: Capacity<256> = 1;
+///    let largest: Capacity<256> = 256;
+///    assert_eq!(smallest.get(), 1);
+///    assert_eq!(largest.get(), Capacity::<256>::MAX);
+/// }
+/// correct()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::Capacity;
+///
+/// #[overloaded_literals]
+/// pub fn oops_zero() {
+///    let capacity: Capacity<256> = 0;
+/// }
+/// oops_zero()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::Capacity;
+///
+/// #[overloaded_literals]
+/// pub fn oops_more_than_max() {
+///    let capacity: Capacity<256> = 257;
+/// }
+/// oops_more_than_max()
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Capacity<const MAX: usize>(usize);
+
+impl<const MAX: usize> Capacity<MAX> {
+    /// The largest capacity allowed.
+    pub const MAX: usize = MAX;
+
+    pub fn get(&self) -> usize {
+        self.0
+    }
+}
+
+impl<const MAX: usize, const LIT: u128> FromLiteralUnsigned<LIT> for Capacity<MAX> {
+    const VALID_LITERAL: u128 = {
+        assert!(LIT >= 1, "Capacity has to be at least 1");
+        assert!(
+            LIT <= MAX as u128,
+            "Capacity cannot be larger than its maximum `MAX`"
+        );
+        LIT
+    };
+
+    #[inline]
+    fn into_self() -> Self {
+        Capacity(<Self as FromLiteralUnsigned<LIT>>::VALID_LITERAL as usize)
+    }
+}
@@ -0,0 +1,179 @@
+use crate::{FromLiteralStr, TypeStr};
+
+/// The value of an HTTP `Content-Type` header, like `"text/html; charset=utf-8"`.
+///
+/// Requires the given string literal to:
+/// - start with a MIME type `type/subtype`, where both parts are non-empty HTTP tokens
+///   (ASCII letters, digits and any of ``!#$%&'*+-.^_`|~``)
+/// - optionally be followed by a single `charset` parameter: a `;`, optional spaces, then `charset=` and a non-empty token.
+///   The parameter name is case-insensitive.
+///
+/// Other parameters (like `boundary`) and quoted parameter values are not supported.
+///
+/// ```rust
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::ContentType;
+///
+/// #[overloaded_literals]
+/// pub fn correct() {
+///    let html: ContentType = "text/html; charset=utf-8";
+///    assert_eq!(html.mime_type(), "text/html");
+///    assert_eq!(html.charset(), Some("utf-8"));
+///
+///    let json: ContentType = "application/json";
+///    assert_eq!(json.mime_type(), "application/json");
+///    assert_eq!(json.charset(), None);
+///
+///    let compact: ContentType = "text/plain;Charset=US-ASCII";
+///    assert_eq!(compact.charset(), Some("US-ASCII"));
+/// }
+/// correct()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::ContentType;
+///
+/// #[overloaded_literals]
+/// pub fn oops_empty_charset() {
+///    let content_type: ContentType = "text/html; charset=";
+/// }
+/// oops_empty_charset()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::ContentType;
+///
+/// #[overloaded_literals]
+/// pub fn oops_missing_subtype() {
+///    let content_type: ContentType = "text";
+/// }
+/// oops_missing_subtype()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::ContentType;
+///
+/// #[overloaded_literals]
+/// pub fn oops_other_parameter() {
+///    let content_type: ContentType = "multipart/form-data; boundary=xyz";
+/// }
+/// oops_other_parameter()
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ContentType(&'static str);
+
+impl ContentType {
+    pub fn as_str(&self) -> &'static str {
+        self.0
+    }
+
+    /// The MIME type `type/subtype`, without the parameter.
+    pub fn mime_type(&self) -> &'static str {
+        match self.0.split_once(';') {
+            Some((mime_type, _)) => mime_type,
+            None => self.0,
+        }
+    }
+
+    /// The value of the `charset` parameter, if any.
+    pub fn charset(&self) -> Option<&'static str> {
+        let (_, parameter) = self.0.split_once(';')?;
+        let (_, charset) = parameter.split_once('=')?;
+        Some(charset)
+    }
+}
+
+/// Whether `byte` can be part of an HTTP token (`tchar` in RFC 9110).
+const fn is_token_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric()
+        || matches!(
+            byte,
+            b'!' | b'#'
+                | b'$'
+                | b'%'
+                | b'&'
+                | b'\''
+                | b'*'
+                | b'+'
+                | b'-'
+                | b'.'
+                | b'^'
+                | b'_'
+                | b'`'
+                | b'|'
+                | b'~'
+        )
+}
+
+/// Returns the index just after the token starting at `index`.
+const fn skip_token(bytes: &[u8], mut index: usize) -> usize {
+    while index < bytes.len() && is_token_byte(bytes[index]) {
+        index += 1;
+    }
+    index
+}
+
+const fn check_content_type(bytes: &[u8]) {
+    let mut index = skip_token(bytes, 0);
+    assert!(
+        index > 0,
+        "ContentType has to start with a type like `text`"
+    );
+    assert!(
+        index < bytes.len() && bytes[index] == b'/',
+        "ContentType type has to be followed by `/` and a subtype"
+    );
+    let subtype_start = index + 1;
+    index = skip_token(bytes, subtype_start);
+    assert!(
+        index > subtype_start,
+        "ContentType type has to be followed by `/` and a subtype"
+    );
+    if index == bytes.len() {
+        return;
+    }
+
+    assert!(
+        bytes[index] == b';',
+        "ContentType MIME type can only be followed by a `; charset=...` parameter"
+    );
+    index += 1;
+    while index < bytes.len() && bytes[index] == b' ' {
+        index += 1;
+    }
+    let name = b"charset=";
+    let mut offset = 0;
+    while offset < name.len() {
+        assert!(
+            index + offset < bytes.len()
+                && bytes[index + offset].to_ascii_lowercase() == name[offset],
+            "ContentType only supports the `charset` parameter"
+        );
+        offset += 1;
+    }
+    let charset_start = index + name.len();
+    index = skip_token(bytes, charset_start);
+    assert!(
+        index > charset_start,
+        "ContentType `charset` parameter cannot be empty"
+    );
+    assert!(
+        index == bytes.len(),
+        "ContentType `charset` has to be a token, and can only be followed by the end of the literal"
+    );
+}
+
+impl<Str: TypeStr> FromLiteralStr<Str> for ContentType {
+    const VALID_LITERAL: &'static str = {
+        check_content_type(Str::STR.as_bytes());
+        Str::STR
+    };
+
+    #[inline]
+    fn into_self() -> Self {
+        ContentType(<Self as FromLiteralStr<Str>>::VALID_LITERAL)
+    }
+}
@@ -0,0 +1,159 @@
+use crate::{FromLiteralFloat, FromLiteralSigned, FromLiteralUnsigned, TypeFloat};
+
+macro_rules! coordinate {
+    ($(#[$attr:meta])* $name:ident, $limit:literal) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+        pub struct $name(f64);
+
+        impl $name {
+            /// The (absolute) largest value allowed, in degrees.
+            pub const LIMIT: f64 = $limit as f64;
+
+            /// The value in degrees.
+            pub fn degrees(&self) -> f64 {
+                self.0
+            }
+        }
+
+        impl<const LIT: u128> FromLiteralUnsigned<LIT> for $name {
+            const VALID_LITERAL: u128 = {
+                assert!(
+                    LIT <= $limit as u128,
+                    concat!("Out of range ", stringify!($name), " (-", $limit, "..=", $limit, " degrees)")
+                );
+                LIT
+            };
+
+            #[inline]
+            fn into_self() -> Self {
+                $name(<Self as FromLiteralUnsigned<LIT>>::VALID_LITERAL as f64)
+            }
+        }
+
+        impl<const LIT: i128> FromLiteralSigned<LIT> for $name {
+            const VALID_LITERAL: i128 = {
+                assert!(
+                    LIT >= -$limit && LIT <= $limit,
+                    concat!("Out of range ", stringify!($name), " (-", $limit, "..=", $limit, " degrees)")
+                );
+                LIT
+            };
+
+            #[inline]
+            fn into_self() -> Self {
+                $name(<Self as FromLiteralSigned<LIT>>::VALID_LITERAL as f64)
+            }
+        }
+
+        impl<TFloat: TypeFloat> FromLiteralFloat<TFloat> for $name {
+            const VALID_LITERAL: f64 = {
+                let val = TFloat::FLOAT;
+                assert!(
+                    val >= -$name::LIMIT && val <= $name::LIMIT,
+                    concat!("Out of range ", stringify!($name), " (-", $limit, "..=", $limit, " degrees)")
+                );
+                val
+            };
+
+            #[inline]
+            fn into_self() -> Self {
+                $name(<Self as FromLiteralFloat<TFloat>>::VALID_LITERAL)
+            }
+        }
+    };
+}
+
+coordinate!(
+    /// A latitude in degrees, north (positive) or south (negative) of the equator.
+    ///
+    /// Can be built from an integer or float literal, which is required to be in the range `-90..=90`.
+    ///
+    /// ```rust
+    /// # use overloaded_literals::overloaded_literals;
+    /// # use overloaded_literals::examples::Latitude;
+    ///
+    /// #[overloaded_literals]
+    /// pub fn correct() {
+    ///    let north_pole: Latitude = 90;
+    ///    let south_pole: Latitude = -90;
+    ///    let amsterdam: Latitude = 52.37;
+    ///    let sydney: Latitude = -33.87;
+    ///    assert_eq!(north_pole.degrees(), 90.0);
+    ///    assert_eq!(south_pole.degrees(), -90.0);
+    ///    assert_eq!(amsterdam.degrees(), 52.37);
+    ///    assert_eq!(sydney.degrees(), -33.87);
+    /// }
+    /// correct()
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use overloaded_literals::overloaded_literals;
+    /// # use overloaded_literals::examples::Latitude;
+    ///
+    /// #[overloaded_literals]
+    /// pub fn oops_too_far_north() {
+    ///    let lat: Latitude = 91;
+    /// }
+    /// oops_too_far_north()
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use overloaded_literals::overloaded_literals;
+    /// # use overloaded_literals::examples::Latitude;
+    ///
+    /// #[overloaded_literals]
+    /// pub fn oops_too_far_south() {
+    ///    let lat: Latitude = -90.5;
+    /// }
+    /// oops_too_far_south()
+    /// ```
+    Latitude,
+    90
+);
+
+coordinate!(
+    /// A longitude in degrees, east (positive) or west (negative) of the prime meridian.
+    ///
+    /// Can be built from an integer or float literal, which is required to be in the range `-180..=180`.
+    ///
+    /// ```rust
+    /// # use overloaded_literals::overloaded_literals;
+    /// # use overloaded_literals::examples::Longitude;
+    ///
+    /// #[overloaded_literals]
+    /// pub fn correct() {
+    ///    let antimeridian: Longitude = 180;
+    ///    let amsterdam: Longitude = 4.9;
+    ///    let new_york: Longitude = -74.01;
+    ///    assert_eq!(antimeridian.degrees(), 180.0);
+    ///    assert_eq!(amsterdam.degrees(), 4.9);
+    ///    assert_eq!(new_york.degrees(), -74.01);
+    /// }
+    /// correct()
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use overloaded_literals::overloaded_literals;
+    /// # use overloaded_literals::examples::Longitude;
+    ///
+    /// #[overloaded_literals]
+    /// pub fn oops_out_of_range() {
+    ///    let lon: Longitude = -181;
+    /// }
+    /// oops_out_of_range()
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use overloaded_literals::overloaded_literals;
+    /// # use overloaded_literals::examples::Longitude;
+    ///
+    /// #[overloaded_literals]
+    /// pub fn oops_out_of_range() {
+    ///    let lon: Longitude = 180.01;
+    /// }
+    /// oops_out_of_range()
+    /// ```
+    Longitude,
+    180
+);
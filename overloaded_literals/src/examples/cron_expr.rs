@@ -0,0 +1,190 @@
+use crate::{FromLiteralStr, TypeStr};
+
+/// A (numeric) five-field cron expression, like `"*/15 9-17 * * 1-5"`.
+///
+/// Requires the given string literal to:
+/// - consist of exactly five fields separated by single spaces:
+///   minute (`0-59`), hour (`0-23`), day of month (`1-31`), month (`1-12`) and day of week (`0-7`, both `0` and `7` are Sunday)
+/// - have fields which are a comma-separated list of items,
+///   where each item is `*`, a number or a range like `1-5`,
+///   optionally followed by a nonzero step like `/15`
+/// - only contain numbers within the range of their field
+///
+/// Names for months and days of the week (like `JAN` or `MON`) and shorthands like `@daily` are not supported.
+///
+/// ```rust
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::CronExpr;
+///
+/// #[overloaded_literals]
+/// pub fn correct() {
+///    let every_minute: CronExpr = "* * * * *";
+///    let office_hours: CronExpr = "*/15 9-17 * * 1-5";
+///    let twice_a_month: CronExpr = "0 0 1,15 * *";
+///    assert_eq!(every_minute.as_str(), "* * * * *");
+///    assert_eq!(office_hours.fields(), ["*/15", "9-17", "*", "*", "1-5"]);
+///    assert_eq!(twice_a_month.fields()[2], "1,15");
+/// }
+/// correct()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::CronExpr;
+///
+/// #[overloaded_literals]
+/// pub fn oops_wrong_field_count() {
+///    let cron: CronExpr = "* * *";
+/// }
+/// oops_wrong_field_count()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::CronExpr;
+///
+/// #[overloaded_literals]
+/// pub fn oops_hour_out_of_range() {
+///    let cron: CronExpr = "0 24 * * *";
+/// }
+/// oops_hour_out_of_range()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::CronExpr;
+///
+/// #[overloaded_literals]
+/// pub fn oops_invalid_character() {
+///    let cron: CronExpr = "0 12 * JAN *";
+/// }
+/// oops_invalid_character()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::CronExpr;
+///
+/// #[overloaded_literals]
+/// pub fn oops_zero_step() {
+///    let cron: CronExpr = "*/0 * * * *";
+/// }
+/// oops_zero_step()
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CronExpr(&'static str);
+
+impl CronExpr {
+    pub fn as_str(&self) -> &'static str {
+        self.0
+    }
+
+    /// The five fields: minute, hour, day of month, month and day of week.
+    pub fn fields(&self) -> [&'static str; 5] {
+        let mut fields = [""; 5];
+        for (field, value) in fields.iter_mut().zip(self.0.split(' ')) {
+            *field = value;
+        }
+        fields
+    }
+}
+
+/// The lowest and highest allowed number of each field.
+const FIELD_RANGES: [(u32, u32); 5] = [(0, 59), (0, 23), (1, 31), (1, 12), (0, 7)];
+
+const fn check_cron_expr(bytes: &[u8]) {
+    let mut field = 0;
+    let mut start = 0;
+    let mut index = 0;
+    while index <= bytes.len() {
+        if index == bytes.len() || bytes[index] == b' ' {
+            assert!(
+                field < FIELD_RANGES.len(),
+                "CronExpr has to consist of exactly five fields"
+            );
+            let (lowest, highest) = FIELD_RANGES[field];
+            check_field(bytes, start, index, lowest, highest);
+            field += 1;
+            start = index + 1;
+        }
+        index += 1;
+    }
+    assert!(
+        field == FIELD_RANGES.len(),
+        "CronExpr has to consist of exactly five fields"
+    );
+}
+
+/// Checks the field `bytes[start..end]`, whose numbers have to be in the range `lowest..=highest`.
+const fn check_field(bytes: &[u8], start: usize, end: usize, lowest: u32, highest: u32) {
+    assert!(
+        start < end,
+        "CronExpr fields have to be separated by a single space"
+    );
+    let mut index = start;
+    loop {
+        assert!(index < end, "CronExpr field cannot end with a comma");
+        if bytes[index] == b'*' {
+            index += 1;
+        } else {
+            let (low, after) = parse_number(bytes, index, end);
+            assert!(
+                low >= lowest && low <= highest,
+                "CronExpr field contains a number outside of the field's range"
+            );
+            index = after;
+            if index < end && bytes[index] == b'-' {
+                let (high, after) = parse_number(bytes, index + 1, end);
+                assert!(
+                    high >= low && high <= highest,
+                    "CronExpr field contains a range which is empty or outside of the field's range"
+                );
+                index = after;
+            }
+        }
+        if index < end && bytes[index] == b'/' {
+            let (step, after) = parse_number(bytes, index + 1, end);
+            assert!(step != 0, "CronExpr step cannot be 0");
+            index = after;
+        }
+        if index == end {
+            break;
+        }
+        assert!(
+            bytes[index] == b',',
+            "Invalid CronExpr field: expected `*`, a number or a range (optionally with a `/step`), or a comma-separated list of those"
+        );
+        index += 1;
+    }
+}
+
+/// Parses the number starting at `bytes[start]` (and before `end`), returning it and the index after it.
+const fn parse_number(bytes: &[u8], start: usize, end: usize) -> (u32, usize) {
+    let mut value = 0;
+    let mut index = start;
+    while index < end && bytes[index].is_ascii_digit() {
+        assert!(
+            value < 100,
+            "CronExpr field contains a number outside of the field's range"
+        );
+        value = value * 10 + (bytes[index] - b'0') as u32;
+        index += 1;
+    }
+    assert!(
+        index > start,
+        "Invalid CronExpr field: expected `*`, a number or a range (optionally with a `/step`), or a comma-separated list of those"
+    );
+    (value, index)
+}
+
+impl<Str: TypeStr> FromLiteralStr<Str> for CronExpr {
+    const VALID_LITERAL: &'static str = {
+        check_cron_expr(Str::STR.as_bytes());
+        Str::STR
+    };
+
+    #[inline]
+    fn into_self() -> Self {
+        CronExpr(<Self as FromLiteralStr<Str>>::VALID_LITERAL)
+    }
+}
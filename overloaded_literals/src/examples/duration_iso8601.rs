@@ -0,0 +1,243 @@
+use crate::{ConstFromLiteralStr, FromLiteralStr, TypeStr};
+use core::time::Duration;
+
+/// A [Duration], written in the ISO-8601 duration format like `"PT1H30M"` or `"P1DT12H"`.
+///
+/// Requires the given string literal to:
+/// - start with `P`
+/// - contain at least one component, which is a number followed by its designator
+/// - only use the designators `W` (weeks) and `D` (days) before the optional `T`,
+///   and `H` (hours), `M` (minutes) and `S` (seconds) after it
+/// - have the components in that order, each occurring at most once
+/// - have at least one component after the `T` (if it is present)
+///
+/// Fractions (with a `.` or `,` and up to nine digits) are only allowed for the seconds, like `"PT0.25S"`.
+/// Years and months are rejected, as their length varies so they cannot be turned into a fixed [Duration].
+///
+/// The literal is parsed only once, at compile time, into its [ConstFromLiteralStr] value,
+/// so it can be used in `const` and `static` items as well.
+///
+/// ```rust
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::DurationIso8601;
+/// use std::time::Duration;
+///
+/// #[overloaded_literals]
+/// pub fn correct() {
+///    let meeting: DurationIso8601 = "PT1H30M";
+///    assert_eq!(meeting.duration(), Duration::from_secs(90 * 60));
+///
+///    let long_weekend: DurationIso8601 = "P3DT12H";
+///    assert_eq!(long_weekend.duration(), Duration::from_secs((3 * 24 + 12) * 60 * 60));
+///
+///    let sprint: DurationIso8601 = "P2W";
+///    assert_eq!(sprint.duration(), Duration::from_secs(14 * 24 * 60 * 60));
+///
+///    let blink: DurationIso8601 = "PT0.25S";
+///    assert_eq!(Duration::from(blink), Duration::from_millis(250));
+///
+///    const TIMEOUT: DurationIso8601 = "PT30S";
+///    assert_eq!(TIMEOUT.duration(), Duration::from_secs(30));
+/// }
+/// correct()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::DurationIso8601;
+///
+/// #[overloaded_literals]
+/// pub fn oops_no_components() {
+///    let duration: DurationIso8601 = "PT";
+/// }
+/// oops_no_components()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::DurationIso8601;
+///
+/// #[overloaded_literals]
+/// pub fn oops_hours_without_t() {
+///    let duration: DurationIso8601 = "P1H";
+/// }
+/// oops_hours_without_t()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::DurationIso8601;
+///
+/// #[overloaded_literals]
+/// pub fn oops_out_of_order() {
+///    let duration: DurationIso8601 = "PT30M1H";
+/// }
+/// oops_out_of_order()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::DurationIso8601;
+///
+/// #[overloaded_literals]
+/// pub fn oops_months() {
+///    let duration: DurationIso8601 = "P1M";
+/// }
+/// oops_months()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::DurationIso8601;
+///
+/// #[overloaded_literals]
+/// pub fn oops_fractional_minutes() {
+///    let duration: DurationIso8601 = "PT1.5M";
+/// }
+/// oops_fractional_minutes()
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct DurationIso8601(Duration);
+
+impl DurationIso8601 {
+    pub fn duration(&self) -> Duration {
+        self.0
+    }
+}
+
+impl From<DurationIso8601> for Duration {
+    fn from(duration: DurationIso8601) -> Duration {
+        duration.0
+    }
+}
+
+const SECS_PER_MINUTE: u64 = 60;
+const SECS_PER_HOUR: u64 = 60 * SECS_PER_MINUTE;
+const SECS_PER_DAY: u64 = 24 * SECS_PER_HOUR;
+const SECS_PER_WEEK: u64 = 7 * SECS_PER_DAY;
+
+/// Parses the digits after the decimal separator, as a number of nanoseconds.
+const fn parse_nanos(bytes: &[u8], start: usize, end: usize) -> u32 {
+    assert!(
+        end > start && end - start <= 9,
+        "DurationIso8601 fraction has to have between one and nine digits"
+    );
+    let mut nanos = 0;
+    let mut index = start;
+    while index < start + 9 {
+        nanos *= 10;
+        if index < end {
+            nanos += (bytes[index] - b'0') as u32;
+        }
+        index += 1;
+    }
+    nanos
+}
+
+const fn parse_duration(literal: &str) -> Duration {
+    let bytes = literal.as_bytes();
+    assert!(
+        !bytes.is_empty() && bytes[0] == b'P',
+        "DurationIso8601 has to start with `P`"
+    );
+    let mut index = 1;
+    let mut in_time = false;
+    // Each designator has a rank, which has to increase from one component to the next.
+    let mut last_rank = 0;
+    let mut secs: u64 = 0;
+    let mut nanos = 0;
+    while index < bytes.len() {
+        if bytes[index] == b'T' {
+            assert!(!in_time, "DurationIso8601 can only contain one `T`");
+            in_time = true;
+            index += 1;
+            assert!(
+                index < bytes.len(),
+                "DurationIso8601 `T` has to be followed by at least one component"
+            );
+            continue;
+        }
+
+        let start = index;
+        let mut value: u64 = 0;
+        while index < bytes.len() && bytes[index].is_ascii_digit() {
+            value = match value.checked_mul(10) {
+                Some(value) => value + (bytes[index] - b'0') as u64,
+                None => panic!("DurationIso8601 component is too large"),
+            };
+            index += 1;
+        }
+        assert!(
+            index > start,
+            "DurationIso8601 components have to start with a number"
+        );
+
+        let mut fraction = None;
+        if index < bytes.len() && (bytes[index] == b'.' || bytes[index] == b',') {
+            index += 1;
+            let fraction_start = index;
+            while index < bytes.len() && bytes[index].is_ascii_digit() {
+                index += 1;
+            }
+            fraction = Some(parse_nanos(bytes, fraction_start, index));
+        }
+
+        assert!(
+            index < bytes.len(),
+            "DurationIso8601 component is missing its designator"
+        );
+        let (rank, secs_per_unit) = match (in_time, bytes[index]) {
+            (false, b'W') => (1, SECS_PER_WEEK),
+            (false, b'D') => (2, SECS_PER_DAY),
+            (true, b'H') => (3, SECS_PER_HOUR),
+            (true, b'M') => (4, SECS_PER_MINUTE),
+            (true, b'S') => (5, 1),
+            (false, b'Y' | b'M') => {
+                panic!("DurationIso8601 cannot contain years or months, as their length varies")
+            }
+            _ => panic!("DurationIso8601 contains an invalid designator (`W` or `D` before the `T`, `H`, `M` or `S` after it)"),
+        };
+        assert!(
+            rank > last_rank,
+            "DurationIso8601 components have to be in order and occur at most once"
+        );
+        if let Some(fraction) = fraction {
+            assert!(
+                rank == 5,
+                "DurationIso8601 only allows a fraction for the seconds"
+            );
+            nanos = fraction;
+        }
+        secs = match value.checked_mul(secs_per_unit) {
+            Some(component) => match secs.checked_add(component) {
+                Some(secs) => secs,
+                None => panic!("DurationIso8601 is too large"),
+            },
+            None => panic!("DurationIso8601 is too large"),
+        };
+        last_rank = rank;
+        index += 1;
+    }
+    assert!(
+        last_rank > 0,
+        "DurationIso8601 has to contain at least one component"
+    );
+    Duration::new(secs, nanos)
+}
+
+impl<Str: TypeStr> FromLiteralStr<Str> for DurationIso8601 {
+    const VALID_LITERAL: &'static str = {
+        parse_duration(Str::STR);
+        Str::STR
+    };
+
+    #[inline]
+    fn into_self() -> Self {
+        <Self as ConstFromLiteralStr<Str>>::VALUE
+    }
+}
+
+impl<Str: TypeStr> ConstFromLiteralStr<Str> for DurationIso8601 {
+    const VALUE: Self =
+        DurationIso8601(parse_duration(<Self as FromLiteralStr<Str>>::VALID_LITERAL));
+}
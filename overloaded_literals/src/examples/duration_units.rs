@@ -0,0 +1,109 @@
+use crate::FromLiteralUnsigned;
+use core::time::Duration;
+
+macro_rules! duration_unit {
+    ($(#[$attr:meta])* $name:ident, $unit:literal, $constructor:ident) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name(Duration);
+
+        impl $name {
+            pub fn duration(&self) -> Duration {
+                self.0
+            }
+        }
+
+        impl From<$name> for Duration {
+            fn from(value: $name) -> Duration {
+                value.0
+            }
+        }
+
+        impl<const LIT: u128> FromLiteralUnsigned<LIT> for $name {
+            const VALID_LITERAL: u128 = {
+                assert!(
+                    LIT <= u64::MAX as u128,
+                    concat!(stringify!($name), " literal is too large: its number of ", $unit, " does not fit in a u64")
+                );
+                LIT
+            };
+
+            #[inline]
+            fn into_self() -> Self {
+                $name(Duration::$constructor(<Self as FromLiteralUnsigned<LIT>>::VALID_LITERAL as u64))
+            }
+        }
+    };
+}
+
+duration_unit!(
+    /// A [Duration] written as a number of milliseconds, like `500`.
+    ///
+    /// Requires the given integer literal to fit in a `u64`.
+    ///
+    /// There is no implementation for [Duration] itself, since a bare number does not say which unit it is in.
+    /// This type (and [Secs](super::Secs)) makes the unit explicit at the place of the type annotation.
+    ///
+    /// ```rust
+    /// # use overloaded_literals::overloaded_literals;
+    /// # use overloaded_literals::examples::Millis;
+    /// use std::time::Duration;
+    ///
+    /// #[overloaded_literals]
+    /// pub fn correct() {
+    ///    let timeout: Millis = 500;
+    ///    assert_eq!(timeout.duration(), Duration::from_millis(500));
+    ///    assert_eq!(Duration::from(timeout).as_secs_f64(), 0.5);
+    /// }
+    /// correct()
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use overloaded_literals::overloaded_literals;
+    /// # use overloaded_literals::examples::Millis;
+    ///
+    /// #[overloaded_literals]
+    /// pub fn oops_overflow() {
+    ///    let timeout: Millis = 18446744073709551616;
+    /// }
+    /// oops_overflow()
+    /// ```
+    Millis,
+    "milliseconds",
+    from_millis
+);
+
+duration_unit!(
+    /// A [Duration] written as a number of seconds, like `30`.
+    ///
+    /// Requires the given integer literal to fit in a `u64`.
+    ///
+    /// See [Millis](super::Millis) for why there is no implementation for [Duration] itself.
+    ///
+    /// ```rust
+    /// # use overloaded_literals::overloaded_literals;
+    /// # use overloaded_literals::examples::Secs;
+    /// use std::time::Duration;
+    ///
+    /// #[overloaded_literals]
+    /// pub fn correct() {
+    ///    let interval: Secs = 30;
+    ///    assert_eq!(interval.duration(), Duration::from_secs(30));
+    /// }
+    /// correct()
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use overloaded_literals::overloaded_literals;
+    /// # use overloaded_literals::examples::Secs;
+    ///
+    /// #[overloaded_literals]
+    /// pub fn oops_overflow() {
+    ///    let interval: Secs = 18446744073709551616;
+    /// }
+    /// oops_overflow()
+    /// ```
+    Secs,
+    "seconds",
+    from_secs
+);
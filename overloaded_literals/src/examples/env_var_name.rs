@@ -0,0 +1,92 @@
+use crate::{FromLiteralStr, TypeStr};
+
+/// The name of an environment variable, following the POSIX rules for portable names.
+///
+/// Requires the given string literal to:
+/// - be non-empty
+/// - only contain ASCII letters, digits and underscores
+/// - not start with a digit
+///
+/// ```rust
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::EnvVarName;
+///
+/// #[overloaded_literals]
+/// pub fn correct() {
+///    let path: EnvVarName = "PATH";
+///    let custom: EnvVarName = "_MY_APP_LOG_LEVEL2";
+///    assert_eq!(path.as_str(), "PATH");
+///    assert_eq!(custom.as_str(), "_MY_APP_LOG_LEVEL2");
+/// }
+/// correct()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::EnvVarName;
+///
+/// #[overloaded_literals]
+/// pub fn oops_starts_with_digit() {
+///    let name: EnvVarName = "1BAD";
+/// }
+/// oops_starts_with_digit()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::EnvVarName;
+///
+/// #[overloaded_literals]
+/// pub fn oops_dash() {
+///    let name: EnvVarName = "MY-VAR";
+/// }
+/// oops_dash()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::EnvVarName;
+///
+/// #[overloaded_literals]
+/// pub fn oops_empty() {
+///    let name: EnvVarName = "";
+/// }
+/// oops_empty()
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EnvVarName(&'static str);
+
+impl EnvVarName {
+    pub fn as_str(&self) -> &'static str {
+        self.0
+    }
+}
+
+const fn is_name_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_'
+}
+
+impl<Str: TypeStr> FromLiteralStr<Str> for EnvVarName {
+    const VALID_LITERAL: &'static str = {
+        let bytes = Str::STR.as_bytes();
+        assert!(!bytes.is_empty(), "EnvVarName cannot be empty");
+        let mut index = 0;
+        while index < bytes.len() {
+            assert!(
+                is_name_byte(bytes[index]),
+                "EnvVarName can only contain ASCII letters, digits and underscores"
+            );
+            index += 1;
+        }
+        assert!(
+            !bytes[0].is_ascii_digit(),
+            "EnvVarName cannot start with a digit"
+        );
+        Str::STR
+    };
+
+    #[inline]
+    fn into_self() -> Self {
+        EnvVarName(<Self as FromLiteralStr<Str>>::VALID_LITERAL)
+    }
+}
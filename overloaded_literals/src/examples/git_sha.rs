@@ -0,0 +1,92 @@
+use crate::{FromLiteralStr, TypeStr};
+
+/// A (possibly abbreviated) Git commit hash, like `"a1b2c3d"`.
+///
+/// Requires the given string literal to:
+/// - be between 7 and 40 characters long (inclusive)
+/// - only contain hexadecimal digits (`0-9`, `a-f` and `A-F`)
+///
+/// ```rust
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::GitSha;
+///
+/// #[overloaded_literals]
+/// pub fn correct() {
+///    let short: GitSha = "a1b2c3d";
+///    let full: GitSha = "a1b2c3d4e5f60718293a4b5c6d7e8f9012345678";
+///    assert_eq!(short.as_str(), "a1b2c3d");
+///    assert!(!short.is_full());
+///    assert!(full.is_full());
+/// }
+/// correct()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::GitSha;
+///
+/// #[overloaded_literals]
+/// pub fn oops_not_hex() {
+///    let sha: GitSha = "xyzxyzxyz";
+/// }
+/// oops_not_hex()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::GitSha;
+///
+/// #[overloaded_literals]
+/// pub fn oops_too_short() {
+///    let sha: GitSha = "abc";
+/// }
+/// oops_too_short()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::GitSha;
+///
+/// #[overloaded_literals]
+/// pub fn oops_too_long() {
+///    let sha: GitSha = "a1b2c3d4e5f60718293a4b5c6d7e8f9012345678a";
+/// }
+/// oops_too_long()
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GitSha(&'static str);
+
+impl GitSha {
+    pub fn as_str(&self) -> &'static str {
+        self.0
+    }
+
+    /// Whether this is a full (SHA-1) hash of 40 characters, rather than an abbreviated one.
+    pub fn is_full(&self) -> bool {
+        self.0.len() == 40
+    }
+}
+
+impl<Str: TypeStr> FromLiteralStr<Str> for GitSha {
+    const VALID_LITERAL: &'static str = {
+        let bytes = Str::STR.as_bytes();
+        assert!(
+            bytes.len() >= 7 && bytes.len() <= 40,
+            "GitSha has to be between 7 and 40 characters long"
+        );
+        let mut index = 0;
+        while index < bytes.len() {
+            assert!(
+                bytes[index].is_ascii_hexdigit(),
+                "GitSha can only contain hexadecimal digits"
+            );
+            index += 1;
+        }
+        Str::STR
+    };
+
+    #[inline]
+    fn into_self() -> Self {
+        GitSha(<Self as FromLiteralStr<Str>>::VALID_LITERAL)
+    }
+}
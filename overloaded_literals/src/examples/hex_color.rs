@@ -0,0 +1,127 @@
+use crate::{ConstFromLiteralStr, FromLiteralStr, TypeStr};
+
+/// An RGB color, written in the CSS-style hexadecimal notation `"#rrggbb"`.
+///
+/// Requires the given string literal to:
+/// - start with a `#`
+/// - be followed by exactly six hexadecimal digits (either lowercase or uppercase)
+///
+/// The literal is parsed only once, at compile time, into its [ConstFromLiteralStr] value,
+/// so it can be used in `const` and `static` items as well.
+///
+/// ```rust
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::HexColor;
+///
+/// #[overloaded_literals]
+/// pub fn correct() {
+///    let orange: HexColor = "#ff8800";
+///    assert_eq!((orange.r(), orange.g(), orange.b()), (0xff, 0x88, 0x00));
+///
+///    let teal: HexColor = "#00A0B0";
+///    assert_eq!(teal.rgb(), (0x00, 0xa0, 0xb0));
+///
+///    const WHITE: HexColor = "#ffffff";
+///    assert_eq!(WHITE.rgb(), (0xff, 0xff, 0xff));
+/// }
+/// correct()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::HexColor;
+///
+/// #[overloaded_literals]
+/// pub fn oops_too_short() {
+///    let color: HexColor = "#fff";
+/// }
+/// oops_too_short()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::HexColor;
+///
+/// #[overloaded_literals]
+/// pub fn oops_not_hex() {
+///    let color: HexColor = "#ff88zz";
+/// }
+/// oops_not_hex()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::HexColor;
+///
+/// #[overloaded_literals]
+/// pub fn oops_missing_hash() {
+///    let color: HexColor = "ff8800";
+/// }
+/// oops_missing_hash()
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct HexColor {
+    r: u8,
+    g: u8,
+    b: u8,
+}
+
+impl HexColor {
+    pub fn r(&self) -> u8 {
+        self.r
+    }
+
+    pub fn g(&self) -> u8 {
+        self.g
+    }
+
+    pub fn b(&self) -> u8 {
+        self.b
+    }
+
+    pub fn rgb(&self) -> (u8, u8, u8) {
+        (self.r, self.g, self.b)
+    }
+}
+
+const fn parse_hex_digit(byte: u8) -> u8 {
+    match byte {
+        b'0'..=b'9' => byte - b'0',
+        b'a'..=b'f' => byte - b'a' + 10,
+        b'A'..=b'F' => byte - b'A' + 10,
+        _ => panic!("HexColor can only contain hexadecimal digits after the `#`"),
+    }
+}
+
+const fn parse_channel(high: u8, low: u8) -> u8 {
+    parse_hex_digit(high) * 16 + parse_hex_digit(low)
+}
+
+const fn parse_hex_color(literal: &str) -> HexColor {
+    let bytes = literal.as_bytes();
+    assert!(
+        bytes.len() == 7 && bytes[0] == b'#',
+        "HexColor must be written as `#rrggbb`"
+    );
+    HexColor {
+        r: parse_channel(bytes[1], bytes[2]),
+        g: parse_channel(bytes[3], bytes[4]),
+        b: parse_channel(bytes[5], bytes[6]),
+    }
+}
+
+impl<Str: TypeStr> FromLiteralStr<Str> for HexColor {
+    const VALID_LITERAL: &'static str = {
+        parse_hex_color(Str::STR);
+        Str::STR
+    };
+
+    #[inline]
+    fn into_self() -> Self {
+        <Self as ConstFromLiteralStr<Str>>::VALUE
+    }
+}
+
+impl<Str: TypeStr> ConstFromLiteralStr<Str> for HexColor {
+    const VALUE: Self = parse_hex_color(<Self as FromLiteralStr<Str>>::VALID_LITERAL);
+}
@@ -0,0 +1,136 @@
+use crate::{FromLiteralStr, TypeStr};
+use core::fmt;
+
+/// The (already escaped) contents of a JSON string, which can be put between double quotes as-is.
+///
+/// Requires the given string literal to:
+/// - not contain any unescaped `"` or control characters (`U+0000` up to and including `U+001F`)
+/// - only contain valid JSON escape sequences: `\"`, `\\`, `\/`, `\b`, `\f`, `\n`, `\r`, `\t` and `\uXXXX` (with four hexadecimal digits)
+///
+/// Note that the escape sequences have to be part of the string itself,
+/// so either escape the backslash (`"a\\tb"`) or use a raw string literal (`r"a\tb"`).
+/// In `"a\tb"`, Rust already turns the `\t` into a tab character, which is not allowed unescaped in JSON.
+///
+/// `Display`ing a `JsonString` writes it surrounded by double quotes, resulting in a valid JSON value.
+///
+/// ```rust
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::JsonString;
+///
+/// #[overloaded_literals]
+/// pub fn correct() {
+///    let plain: JsonString = "hello";
+///    let escaped: JsonString = r#"say \"hi\"\n"#;
+///    let unicode: JsonString = r"caf\u00e9 \u2615";
+///    assert_eq!(plain.as_str(), "hello");
+///    assert_eq!(escaped.to_string(), r#""say \"hi\"\n""#);
+///    assert_eq!(unicode.as_str(), r"caf\u00e9 \u2615");
+/// }
+/// correct()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::JsonString;
+///
+/// #[overloaded_literals]
+/// pub fn oops_unescaped_quote() {
+///    let json: JsonString = r#"say "hi""#;
+/// }
+/// oops_unescaped_quote()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::JsonString;
+///
+/// #[overloaded_literals]
+/// pub fn oops_control_character() {
+///    let json: JsonString = "a\tb";
+/// }
+/// oops_control_character()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::JsonString;
+///
+/// #[overloaded_literals]
+/// pub fn oops_invalid_escape() {
+///    let json: JsonString = r"C:\Users";
+/// }
+/// oops_invalid_escape()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::JsonString;
+///
+/// #[overloaded_literals]
+/// pub fn oops_short_unicode_escape() {
+///    let json: JsonString = r"\u00e";
+/// }
+/// oops_short_unicode_escape()
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JsonString(&'static str);
+
+impl JsonString {
+    /// The contents of the string, without surrounding quotes.
+    pub fn as_str(&self) -> &'static str {
+        self.0
+    }
+}
+
+impl fmt::Display for JsonString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{}\"", self.0)
+    }
+}
+
+const fn check_json_string(bytes: &[u8]) {
+    let mut index = 0;
+    while index < bytes.len() {
+        let byte = bytes[index];
+        assert!(
+            byte >= 0x20,
+            "JsonString cannot contain unescaped control characters"
+        );
+        assert!(byte != b'"', "JsonString cannot contain unescaped `\"`");
+        if byte == b'\\' {
+            assert!(
+                index + 1 < bytes.len(),
+                "JsonString cannot end in an unfinished escape sequence"
+            );
+            match bytes[index + 1] {
+                b'"' | b'\\' | b'/' | b'b' | b'f' | b'n' | b'r' | b't' => index += 2,
+                b'u' => {
+                    assert!(
+                        index + 5 < bytes.len()
+                            && bytes[index + 2].is_ascii_hexdigit()
+                            && bytes[index + 3].is_ascii_hexdigit()
+                            && bytes[index + 4].is_ascii_hexdigit()
+                            && bytes[index + 5].is_ascii_hexdigit(),
+                        "JsonString `\\u` escape has to be followed by four hexadecimal digits"
+                    );
+                    index += 6;
+                }
+                _ => panic!("JsonString contains an invalid escape sequence"),
+            }
+        } else {
+            index += 1;
+        }
+    }
+}
+
+impl<Str: TypeStr> FromLiteralStr<Str> for JsonString {
+    const VALID_LITERAL: &'static str = {
+        check_json_string(Str::STR.as_bytes());
+        Str::STR
+    };
+
+    #[inline]
+    fn into_self() -> Self {
+        JsonString(<Self as FromLiteralStr<Str>>::VALID_LITERAL)
+    }
+}
@@ -0,0 +1,133 @@
+use crate::{FromLiteralStr, TypeStr};
+
+/// A (simplified) BCP-47 language tag, like `"en"` or `"en-US"`.
+///
+/// Requires the given string literal to:
+/// - start with a language subtag of two or three lowercase ASCII letters
+/// - optionally be followed by a `-` and a region subtag,
+///   which is either two uppercase ASCII letters (`"en-US"`) or three digits (`"es-419"`)
+///
+/// Other subtags (scripts, variants, extensions) are not supported.
+///
+/// ```rust
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::LanguageTag;
+///
+/// #[overloaded_literals]
+/// pub fn correct() {
+///    let tag: LanguageTag = "en-US";
+///    assert_eq!(tag.language(), "en");
+///    assert_eq!(tag.region(), Some("US"));
+///
+///    let tag: LanguageTag = "nld";
+///    assert_eq!(tag.language(), "nld");
+///    assert_eq!(tag.region(), None);
+///
+///    let tag: LanguageTag = "es-419";
+///    assert_eq!(tag.region(), Some("419"));
+/// }
+/// correct()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::LanguageTag;
+///
+/// #[overloaded_literals]
+/// pub fn oops_not_a_subtag() {
+///    let tag: LanguageTag = "english";
+/// }
+/// oops_not_a_subtag()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::LanguageTag;
+///
+/// #[overloaded_literals]
+/// pub fn oops_wrong_casing() {
+///    let tag: LanguageTag = "EN-us";
+/// }
+/// oops_wrong_casing()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::LanguageTag;
+///
+/// #[overloaded_literals]
+/// pub fn oops_region_too_long() {
+///    let tag: LanguageTag = "en-USA";
+/// }
+/// oops_region_too_long()
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LanguageTag(&'static str);
+
+impl LanguageTag {
+    pub fn as_str(&self) -> &'static str {
+        self.0
+    }
+
+    /// The language subtag, like `"en"`.
+    pub fn language(&self) -> &'static str {
+        let len = language_len(self.0.as_bytes());
+        &self.0[..len]
+    }
+
+    /// The region subtag (if any), like `"US"`.
+    pub fn region(&self) -> Option<&'static str> {
+        let len = language_len(self.0.as_bytes());
+        self.0.get(len + 1..)
+    }
+}
+
+/// Length of the language subtag at the start of `bytes`.
+const fn language_len(bytes: &[u8]) -> usize {
+    let mut len = 0;
+    while len < bytes.len() && bytes[len] != b'-' {
+        len += 1;
+    }
+    len
+}
+
+/// Whether all of `bytes[start..end]` are in the range `lowest..=highest`.
+const fn all_in_range(bytes: &[u8], start: usize, end: usize, lowest: u8, highest: u8) -> bool {
+    let mut index = start;
+    while index < end {
+        if bytes[index] < lowest || bytes[index] > highest {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
+
+impl<Str: TypeStr> FromLiteralStr<Str> for LanguageTag {
+    const VALID_LITERAL: &'static str = {
+        let bytes = Str::STR.as_bytes();
+        let language_len = language_len(bytes);
+        assert!(
+            (language_len == 2 || language_len == 3)
+                && all_in_range(bytes, 0, language_len, b'a', b'z'),
+            "LanguageTag has to start with a language subtag of two or three lowercase ASCII letters"
+        );
+        if language_len < bytes.len() {
+            let region_len = bytes.len() - language_len - 1;
+            let is_letter_region =
+                region_len == 2 && all_in_range(bytes, language_len + 1, bytes.len(), b'A', b'Z');
+            let is_numeric_region =
+                region_len == 3 && all_in_range(bytes, language_len + 1, bytes.len(), b'0', b'9');
+            assert!(
+                is_letter_region || is_numeric_region,
+                "LanguageTag region subtag has to be two uppercase ASCII letters or three digits"
+            );
+        }
+        Str::STR
+    };
+
+    #[inline]
+    fn into_self() -> Self {
+        LanguageTag(<Self as FromLiteralStr<Str>>::VALID_LITERAL)
+    }
+}
@@ -0,0 +1,80 @@
+use crate::{FromLiteralByteStr, TypeByteStr};
+
+/// A 48-bit MAC address, written as a byte string of exactly six bytes, like `b"\x00\x1A\x2B\x3C\x4D\x5E"`.
+///
+/// Requires the given byte string literal to be exactly six bytes long.
+///
+/// ```rust
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::MacAddress;
+///
+/// #[overloaded_literals]
+/// pub fn correct() {
+///    let mac: MacAddress = b"\x00\x1A\x2B\x3C\x4D\x5E";
+///    assert_eq!(mac.octets(), [0x00, 0x1A, 0x2B, 0x3C, 0x4D, 0x5E]);
+///    assert_eq!(mac.to_string(), "00:1a:2b:3c:4d:5e");
+///
+///    let broadcast: MacAddress = b"\xFF\xFF\xFF\xFF\xFF\xFF";
+///    assert!(broadcast.is_broadcast());
+/// }
+/// correct()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::MacAddress;
+///
+/// #[overloaded_literals]
+/// pub fn oops_too_short() {
+///    let mac: MacAddress = b"\x00\x1A\x2B\x3C\x4D";
+/// }
+/// oops_too_short()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::MacAddress;
+///
+/// #[overloaded_literals]
+/// pub fn oops_too_long() {
+///    let mac: MacAddress = b"\x00\x1A\x2B\x3C\x4D\x5E\x6F";
+/// }
+/// oops_too_long()
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct MacAddress([u8; 6]);
+
+impl MacAddress {
+    pub fn octets(&self) -> [u8; 6] {
+        self.0
+    }
+
+    /// Whether this is the broadcast address `ff:ff:ff:ff:ff:ff`.
+    pub fn is_broadcast(&self) -> bool {
+        self.0 == [0xFF; 6]
+    }
+}
+
+/// Formats the address as six lowercase hexadecimal octets separated by colons, like `00:1a:2b:3c:4d:5e`.
+impl core::fmt::Display for MacAddress {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let [a, b, c, d, e, g] = self.0;
+        write!(f, "{a:02x}:{b:02x}:{c:02x}:{d:02x}:{e:02x}:{g:02x}")
+    }
+}
+
+impl<Bytes: TypeByteStr> FromLiteralByteStr<Bytes> for MacAddress {
+    const VALID_LITERAL: &'static [u8] = {
+        assert!(
+            Bytes::BYTES.len() == 6,
+            "MacAddress has to be exactly six bytes long"
+        );
+        Bytes::BYTES
+    };
+
+    #[inline]
+    fn into_self() -> Self {
+        let bytes = <Self as FromLiteralByteStr<Bytes>>::VALID_LITERAL;
+        MacAddress([bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5]])
+    }
+}
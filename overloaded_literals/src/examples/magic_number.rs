@@ -0,0 +1,74 @@
+use crate::FromLiteralUnsigned;
+
+/// A protocol constant which has to be exactly `EXPECTED`, like the `0xCAFEBABE` at the start of a Java class file.
+///
+/// Requires the given integer literal to:
+/// - be equal to `EXPECTED`
+/// - fit in a `u64`
+///
+/// Since there is only a single valid literal, this mainly documents (and checks) at the place of use
+/// that a value is the constant it is supposed to be.
+///
+/// ```rust
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::MagicNumber;
+///
+/// #[overloaded_literals]
+/// pub fn correct() {
+///    let class_file: MagicNumber<0xCAFEBABE> = 0xCAFEBABE;
+///    assert_eq!(class_file.get(), 0xCAFEBABE);
+///
+///    let png: MagicNumber<0x89504E470D0A1A0A> = 0x89504E470D0A1A0A;
+///    assert_eq!(png.get().to_be_bytes(), *b"\x89PNG\r\n\x1a\n");
+/// }
+/// correct()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::MagicNumber;
+///
+/// #[overloaded_literals]
+/// pub fn oops_wrong_value() {
+///    let class_file: MagicNumber<0xCAFEBABE> = 0xCAFEBABF;
+/// }
+/// oops_wrong_value()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::MagicNumber;
+///
+/// #[overloaded_literals]
+/// pub fn oops_too_large() {
+///    let huge: MagicNumber<0x1_0000_0000_0000_0000> = 0x1_0000_0000_0000_0000;
+/// }
+/// oops_too_large()
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MagicNumber<const EXPECTED: u128>(u64);
+
+impl<const EXPECTED: u128> MagicNumber<EXPECTED> {
+    pub fn get(&self) -> u64 {
+        self.0
+    }
+}
+
+impl<const EXPECTED: u128, const LIT: u128> FromLiteralUnsigned<LIT> for MagicNumber<EXPECTED> {
+    const VALID_LITERAL: u128 = {
+        assert!(
+            LIT == EXPECTED,
+            "MagicNumber literal is not equal to its `EXPECTED` value"
+        );
+        assert!(
+            LIT <= u64::MAX as u128,
+            "MagicNumber literal does not fit in a u64"
+        );
+        LIT
+    };
+
+    #[inline]
+    fn into_self() -> Self {
+        MagicNumber(<Self as FromLiteralUnsigned<LIT>>::VALID_LITERAL as u64)
+    }
+}
@@ -0,0 +1,63 @@
+//! Example datatypes which implement the `FromLiteral*` traits.
+//!
+//! Each of these performs its input validation at compile-time,
+//! so an invalid literal results in a compile error rather than a runtime panic.
+//!
+//! They are useful in their own right,
+//! but are mainly intended as inspiration for implementing the traits for your own datatypes.
+//! Feel free to copy and adapt them.
+mod ansi_color;
+mod ascii_char;
+mod base58_addr;
+mod byte_units;
+mod capacity;
+mod content_type;
+mod coordinate;
+mod cron_expr;
+mod duration_iso8601;
+mod duration_units;
+mod env_var_name;
+mod git_sha;
+mod hex_color;
+mod json_string;
+mod language_tag;
+mod mac_address;
+mod magic_number;
+mod multiple;
+mod port;
+mod posix_permissions;
+mod printable_byte;
+mod ratio;
+mod rgb_color;
+mod size_align;
+mod slug;
+mod sql_ident;
+mod time_of_day;
+
+pub use ansi_color::AnsiColor;
+pub use ascii_char::AsciiChar;
+pub use base58_addr::Base58Addr;
+pub use byte_units::{Gib, Kib, Mib};
+pub use capacity::Capacity;
+pub use content_type::ContentType;
+pub use coordinate::{Latitude, Longitude};
+pub use cron_expr::CronExpr;
+pub use duration_iso8601::DurationIso8601;
+pub use duration_units::{Millis, Secs};
+pub use env_var_name::EnvVarName;
+pub use git_sha::GitSha;
+pub use hex_color::HexColor;
+pub use json_string::JsonString;
+pub use language_tag::LanguageTag;
+pub use mac_address::MacAddress;
+pub use magic_number::MagicNumber;
+pub use multiple::Multiple;
+pub use port::Port;
+pub use posix_permissions::PosixPermissions;
+pub use printable_byte::PrintableByte;
+pub use ratio::Ratio;
+pub use rgb_color::RgbColor;
+pub use size_align::SizeAlign;
+pub use slug::Slug;
+pub use sql_ident::SqlIdent;
+pub use time_of_day::TimeOfDay;
@@ -0,0 +1,85 @@
+use crate::FromLiteralUnsigned;
+
+/// An integer which is a multiple of `OF`, like a buffer size which is a whole number of pages.
+///
+/// Requires the given integer literal to:
+/// - be divisible by `OF` (which itself cannot be zero)
+/// - fit in a `u64`
+///
+/// Zero is a multiple of anything, so it is allowed.
+///
+/// See [SizeAlign](super::SizeAlign) for a size which has to be a multiple of a power-of-two alignment.
+///
+/// ```rust
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::Multiple;
+///
+/// #[overloaded_literals]
+/// pub fn correct() {
+///    let buffer: Multiple<4096> = 8192;
+///    assert_eq!(buffer.get(), 8192);
+///    assert_eq!(buffer.count(), 2);
+///
+///    let dozens: Multiple<12> = 144;
+///    assert_eq!(dozens.count(), 12);
+/// }
+/// correct()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::Multiple;
+///
+/// #[overloaded_literals]
+/// pub fn oops_not_a_multiple() {
+///    let buffer: Multiple<4096> = 5000;
+/// }
+/// oops_not_a_multiple()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::Multiple;
+///
+/// #[overloaded_literals]
+/// pub fn oops_multiple_of_zero() {
+///    let nothing: Multiple<0> = 0;
+/// }
+/// oops_multiple_of_zero()
+/// ```
+// No `Default`: `Multiple::<0>::default()` would sidestep the check that `OF` is nonzero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Multiple<const OF: u64>(u64);
+
+impl<const OF: u64> Multiple<OF> {
+    pub fn get(&self) -> u64 {
+        self.0
+    }
+
+    /// How many times `OF` fits in the value.
+    pub fn count(&self) -> u64 {
+        self.0 / OF
+    }
+}
+
+impl<const OF: u64, const LIT: u128> FromLiteralUnsigned<LIT> for Multiple<OF> {
+    // `u128::is_multiple_of` is only available since Rust 1.87
+    #[allow(unknown_lints, clippy::manual_is_multiple_of)]
+    const VALID_LITERAL: u128 = {
+        assert!(OF != 0, "Multiple has to be a multiple of a nonzero number");
+        assert!(
+            LIT % (OF as u128) == 0,
+            "Multiple literal is not a multiple of `OF`"
+        );
+        assert!(
+            LIT <= u64::MAX as u128,
+            "Multiple literal does not fit in a u64"
+        );
+        LIT
+    };
+
+    #[inline]
+    fn into_self() -> Self {
+        Multiple(<Self as FromLiteralUnsigned<LIT>>::VALID_LITERAL as u64)
+    }
+}
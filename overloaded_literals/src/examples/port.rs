@@ -0,0 +1,100 @@
+use crate::{ConstFromLiteralUnsigned, FromLiteralUnsigned, LiteralError, TryFromLiteralValue};
+
+/// A TCP or UDP port number.
+///
+/// Requires the given integer literal to be in the range `1..=65535`.
+///
+/// `0` is rejected: when binding a socket it does not refer to a particular port,
+/// but asks the operating system to pick any free one.
+/// If that is what you want, it is clearer to spell it out in your code rather than use a `Port` for it.
+///
+/// It implements [ConstFromLiteralUnsigned] as well, so it can be used in `const` and `static` items.
+///
+/// ```rust
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::Port;
+///
+/// #[overloaded_literals]
+/// pub fn correct() {
+///    let http: Port = 80;
+///    let max: Port = 65535;
+///    assert_eq!(http.get(), 80);
+///    assert_eq!(max.get(), u16::MAX);
+///
+///    // Also usable in constants, through `ConstFromLiteralUnsigned`
+///    const HTTPS: Port = 443;
+///    assert_eq!(HTTPS.get(), 443);
+/// }
+/// correct()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::Port;
+///
+/// #[overloaded_literals]
+/// pub fn oops_too_large() {
+///    let port: Port = 70000;
+/// }
+/// oops_too_large()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::Port;
+///
+/// #[overloaded_literals]
+/// pub fn oops_zero() {
+///    let port: Port = 0;
+/// }
+/// oops_zero()
+/// ```
+///
+/// A port which is only known at runtime (like one read from a config file) goes through the same checks
+/// with [try_from_value](TryFromLiteralValue::try_from_value):
+///
+/// ```rust
+/// # use overloaded_literals::examples::Port;
+/// use overloaded_literals::{LiteralError, TryFromLiteralValue};
+///
+/// let from_config = |port: u128| Port::try_from_value(port);
+/// assert_eq!(from_config(8080).map(|port| port.get()), Ok(8080));
+/// assert_eq!(from_config(0), Err(LiteralError::Zero));
+/// assert_eq!(from_config(70000), Err(LiteralError::OutOfRange));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Port(u16);
+
+impl Port {
+    pub fn get(&self) -> u16 {
+        self.0
+    }
+}
+
+impl<const LIT: u128> FromLiteralUnsigned<LIT> for Port {
+    const VALID_LITERAL: u128 = {
+        assert!(LIT != 0, "Port cannot be 0");
+        assert!(LIT <= u16::MAX as u128, "Out of range Port (1..=65535)");
+        LIT
+    };
+
+    #[inline]
+    fn into_self() -> Self {
+        <Self as ConstFromLiteralUnsigned<LIT>>::VALUE
+    }
+}
+
+impl<const LIT: u128> ConstFromLiteralUnsigned<LIT> for Port {
+    const VALUE: Self = Port(<Self as FromLiteralUnsigned<LIT>>::VALID_LITERAL as u16);
+}
+
+impl TryFromLiteralValue<u128> for Port {
+    #[inline]
+    fn try_from_value(value: u128) -> Result<Self, LiteralError> {
+        match value {
+            0 => Err(LiteralError::Zero),
+            1..=65535 => Ok(Port(value as u16)),
+            _ => Err(LiteralError::OutOfRange),
+        }
+    }
+}
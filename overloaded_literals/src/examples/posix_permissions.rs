@@ -0,0 +1,124 @@
+use crate::{ConstFromLiteralStr, FromLiteralStr, TypeStr};
+
+/// A POSIX file mode, written as an octal string like `"0755"` or `"04755"`.
+///
+/// Requires the given string literal to:
+/// - start with a `0`
+/// - be followed by three or four octal digits (`0-7`),
+///   so the mode is at most `0o7777` (including the setuid, setgid and sticky bits)
+///
+/// The literal is parsed only once, at compile time, into its [ConstFromLiteralStr] value,
+/// so it can be used in `const` and `static` items as well.
+///
+/// ```rust
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::PosixPermissions;
+///
+/// #[overloaded_literals]
+/// pub fn correct() {
+///    let executable: PosixPermissions = "0755";
+///    assert_eq!(executable.mode(), 0o755);
+///    assert_eq!(executable.to_string(), "0755");
+///
+///    let setuid: PosixPermissions = "04755";
+///    assert_eq!(setuid.mode(), 0o4755);
+///    assert_eq!(setuid.to_string(), "04755");
+///
+///    const PRIVATE: PosixPermissions = "0600";
+///    assert_eq!(PRIVATE.mode(), 0o600);
+/// }
+/// correct()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::PosixPermissions;
+///
+/// #[overloaded_literals]
+/// pub fn oops_not_octal() {
+///    let permissions: PosixPermissions = "0999";
+/// }
+/// oops_not_octal()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::PosixPermissions;
+///
+/// #[overloaded_literals]
+/// pub fn oops_no_leading_zero() {
+///    let permissions: PosixPermissions = "755";
+/// }
+/// oops_no_leading_zero()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::PosixPermissions;
+///
+/// #[overloaded_literals]
+/// pub fn oops_too_large() {
+///    let permissions: PosixPermissions = "017777";
+/// }
+/// oops_too_large()
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct PosixPermissions(u16);
+
+impl PosixPermissions {
+    /// The mode as a number, like `0o755`.
+    pub fn mode(&self) -> u16 {
+        self.0
+    }
+}
+
+/// Formats the mode as it is usually written, with a leading zero, like `0755`.
+impl core::fmt::Display for PosixPermissions {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.0 > 0o777 {
+            write!(f, "0{:04o}", self.0)
+        } else {
+            write!(f, "0{:03o}", self.0)
+        }
+    }
+}
+
+const fn parse_posix_permissions(literal: &str) -> PosixPermissions {
+    let bytes = literal.as_bytes();
+    assert!(
+        !bytes.is_empty() && bytes[0] == b'0',
+        "PosixPermissions has to start with a `0`, like `0755`"
+    );
+    assert!(
+        bytes.len() == 4 || bytes.len() == 5,
+        "PosixPermissions has to have three or four octal digits after the leading `0` (at most `07777`)"
+    );
+    let mut mode = 0;
+    let mut index = 1;
+    while index < bytes.len() {
+        let byte = bytes[index];
+        assert!(
+            byte >= b'0' && byte <= b'7',
+            "PosixPermissions can only contain octal digits (`0-7`)"
+        );
+        mode = mode * 8 + (byte - b'0') as u16;
+        index += 1;
+    }
+    PosixPermissions(mode)
+}
+
+impl<Str: TypeStr> FromLiteralStr<Str> for PosixPermissions {
+    const VALID_LITERAL: &'static str = {
+        parse_posix_permissions(Str::STR);
+        Str::STR
+    };
+
+    #[inline]
+    fn into_self() -> Self {
+        <Self as ConstFromLiteralStr<Str>>::VALUE
+    }
+}
+
+impl<Str: TypeStr> ConstFromLiteralStr<Str> for PosixPermissions {
+    const VALUE: Self = parse_posix_permissions(<Self as FromLiteralStr<Str>>::VALID_LITERAL);
+}
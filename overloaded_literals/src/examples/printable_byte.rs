@@ -0,0 +1,111 @@
+use crate::{FromLiteralByte, LiteralError, TryFromLiteralValue};
+
+/// A printable ASCII byte: a space (`b' '`) up to and including a tilde (`b'~'`).
+///
+/// Requires the given byte literal to be printable ASCII,
+/// so control characters like `b'\n'` and non-ASCII bytes like `b'\xFF'` are rejected.
+///
+/// Since the literal is validated at compile time,
+/// `into_self()` builds the value with the unchecked constructor [PrintableByte::new_unchecked].
+///
+/// ```rust
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::PrintableByte;
+///
+/// #[overloaded_literals]
+/// pub fn correct() {
+///    let letter: PrintableByte = b'A';
+///    let space: PrintableByte = b' ';
+///    assert_eq!(letter.get(), 65);
+///    assert_eq!(letter.as_char(), 'A');
+///    assert_eq!(space.get(), 32);
+/// }
+/// correct()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::PrintableByte;
+///
+/// #[overloaded_literals]
+/// pub fn oops_control_character() {
+///    let newline: PrintableByte = b'\n';
+/// }
+/// oops_control_character()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::PrintableByte;
+///
+/// #[overloaded_literals]
+/// pub fn oops_not_ascii() {
+///    let byte: PrintableByte = b'\xFF';
+/// }
+/// oops_not_ascii()
+/// ```
+///
+/// A byte which is only known at runtime goes through the same check with [try_from_value](TryFromLiteralValue::try_from_value):
+///
+/// ```rust
+/// # use overloaded_literals::examples::PrintableByte;
+/// use overloaded_literals::{LiteralError, TryFromLiteralValue};
+///
+/// assert_eq!(PrintableByte::try_from_value(b'~').map(|byte| byte.get()), Ok(126));
+/// assert_eq!(PrintableByte::try_from_value(b'\x7f'), Err(LiteralError::InvalidByte));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PrintableByte(u8);
+
+impl PrintableByte {
+    /// Returns `None` if `byte` is not printable ASCII.
+    pub const fn new(byte: u8) -> Option<Self> {
+        if is_printable(byte) {
+            Some(PrintableByte(byte))
+        } else {
+            None
+        }
+    }
+
+    /// # Safety
+    /// `byte` has to be printable ASCII (`b' '..=b'~'`).
+    pub const unsafe fn new_unchecked(byte: u8) -> Self {
+        PrintableByte(byte)
+    }
+
+    pub fn get(&self) -> u8 {
+        self.0
+    }
+
+    pub fn as_char(&self) -> char {
+        self.0 as char
+    }
+}
+
+const fn is_printable(byte: u8) -> bool {
+    byte >= b' ' && byte <= b'~'
+}
+
+impl<const LIT: u8> FromLiteralByte<LIT> for PrintableByte {
+    const VALID_LITERAL: u8 = {
+        assert!(
+            is_printable(LIT),
+            "PrintableByte has to be a printable ASCII character (`b' '` up to `b'~'`)"
+        );
+        LIT
+    };
+
+    #[inline]
+    fn into_self() -> Self {
+        let byte = <Self as FromLiteralByte<LIT>>::VALID_LITERAL;
+        // SAFETY: `VALID_LITERAL` only compiles for printable bytes
+        unsafe { PrintableByte::new_unchecked(byte) }
+    }
+}
+
+impl TryFromLiteralValue<u8> for PrintableByte {
+    #[inline]
+    fn try_from_value(value: u8) -> Result<Self, LiteralError> {
+        PrintableByte::new(value).ok_or(LiteralError::InvalidByte)
+    }
+}
@@ -0,0 +1,201 @@
+use crate::{ConstFromLiteralStr, FromLiteralSigned, FromLiteralStr, FromLiteralUnsigned, TypeStr};
+
+/// A rational number, like `3/4`.
+///
+/// Can be built from:
+/// - an integer literal (like `3` or `-3`), which results in a whole number (`3/1`).
+///   Requires the literal to fit in an `i64`.
+/// - a string literal of the form `"numerator/denominator"` (like `"3/4"` or `"-3/4"`).
+///   Requires the numerator to fit in an `i64`, and the denominator to be a nonzero `u64`.
+///
+/// The fraction is stored as written; `"6/8"` is not reduced to `3/4`.
+///
+/// A string literal is parsed only once, at compile time, into its [ConstFromLiteralStr] value,
+/// so it can be used in `const` and `static` items as well.
+///
+/// ```rust
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::Ratio;
+///
+/// #[overloaded_literals]
+/// pub fn correct() {
+///    let whole: Ratio = 3;
+///    assert_eq!((whole.numerator(), whole.denominator()), (3, 1));
+///
+///    let negative: Ratio = -3;
+///    assert_eq!(negative.numerator(), -3);
+///
+///    let three_quarters: Ratio = "3/4";
+///    assert_eq!((three_quarters.numerator(), three_quarters.denominator()), (3, 4));
+///
+///    let negative_half: Ratio = "-1/2";
+///    assert_eq!(negative_half.to_f64(), -0.5);
+///
+///    const GOLDEN: Ratio = "1618/1000";
+///    assert_eq!(GOLDEN.denominator(), 1000);
+/// }
+/// correct()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::Ratio;
+///
+/// #[overloaded_literals]
+/// pub fn oops_zero_denominator() {
+///    let ratio: Ratio = "1/0";
+/// }
+/// oops_zero_denominator()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::Ratio;
+///
+/// #[overloaded_literals]
+/// pub fn oops_not_a_fraction() {
+///    let ratio: Ratio = "3 / 4";
+/// }
+/// oops_not_a_fraction()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::Ratio;
+///
+/// #[overloaded_literals]
+/// pub fn oops_too_large() {
+///    let ratio: Ratio = 9223372036854775808;
+/// }
+/// oops_too_large()
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Ratio {
+    numerator: i64,
+    denominator: u64,
+}
+
+impl Ratio {
+    pub fn numerator(&self) -> i64 {
+        self.numerator
+    }
+
+    /// The denominator, which is never zero.
+    pub fn denominator(&self) -> u64 {
+        self.denominator
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+}
+
+impl<const LIT: u128> FromLiteralUnsigned<LIT> for Ratio {
+    const VALID_LITERAL: u128 = {
+        assert!(
+            LIT <= i64::MAX as u128,
+            "Ratio numerator has to fit in an i64"
+        );
+        LIT
+    };
+
+    #[inline]
+    fn into_self() -> Self {
+        Ratio {
+            numerator: <Self as FromLiteralUnsigned<LIT>>::VALID_LITERAL as i64,
+            denominator: 1,
+        }
+    }
+}
+
+impl<const LIT: i128> FromLiteralSigned<LIT> for Ratio {
+    const VALID_LITERAL: i128 = {
+        assert!(
+            LIT >= i64::MIN as i128 && LIT <= i64::MAX as i128,
+            "Ratio numerator has to fit in an i64"
+        );
+        LIT
+    };
+
+    #[inline]
+    fn into_self() -> Self {
+        Ratio {
+            numerator: <Self as FromLiteralSigned<LIT>>::VALID_LITERAL as i64,
+            denominator: 1,
+        }
+    }
+}
+
+/// Parses the digits in `bytes[start..end]` as a number.
+const fn parse_digits(bytes: &[u8], start: usize, end: usize) -> u64 {
+    assert!(
+        start < end,
+        "Ratio has to be written as `numerator/denominator`, like \"3/4\""
+    );
+    let mut value: u64 = 0;
+    let mut index = start;
+    while index < end {
+        assert!(
+            bytes[index].is_ascii_digit(),
+            "Ratio has to be written as `numerator/denominator`, like \"3/4\""
+        );
+        let digit = (bytes[index] - b'0') as u64;
+        assert!(
+            value <= (u64::MAX - digit) / 10,
+            "Ratio numerator has to fit in an i64 and denominator in a u64"
+        );
+        value = value * 10 + digit;
+        index += 1;
+    }
+    value
+}
+
+/// Parses `"numerator/denominator"` into its two parts.
+const fn parse_ratio(str: &str) -> (i64, u64) {
+    let bytes = str.as_bytes();
+    let negative = !bytes.is_empty() && bytes[0] == b'-';
+    let start = if negative { 1 } else { 0 };
+    let mut slash = start;
+    while slash < bytes.len() && bytes[slash] != b'/' {
+        slash += 1;
+    }
+    let magnitude = parse_digits(bytes, start, slash);
+    let denominator = parse_digits(bytes, slash + 1, bytes.len());
+    assert!(denominator != 0, "Ratio denominator cannot be 0");
+    let numerator = if negative {
+        assert!(
+            magnitude <= i64::MIN.unsigned_abs(),
+            "Ratio numerator has to fit in an i64"
+        );
+        (magnitude as i64).wrapping_neg()
+    } else {
+        assert!(
+            magnitude <= i64::MAX as u64,
+            "Ratio numerator has to fit in an i64"
+        );
+        magnitude as i64
+    };
+    (numerator, denominator)
+}
+
+impl<Str: TypeStr> FromLiteralStr<Str> for Ratio {
+    const VALID_LITERAL: &'static str = {
+        parse_ratio(Str::STR);
+        Str::STR
+    };
+
+    #[inline]
+    fn into_self() -> Self {
+        <Self as ConstFromLiteralStr<Str>>::VALUE
+    }
+}
+
+impl<Str: TypeStr> ConstFromLiteralStr<Str> for Ratio {
+    const VALUE: Self = {
+        let (numerator, denominator) = parse_ratio(<Self as FromLiteralStr<Str>>::VALID_LITERAL);
+        Ratio {
+            numerator,
+            denominator,
+        }
+    };
+}
@@ -0,0 +1,77 @@
+use crate::FromLiteralUnsigned;
+
+/// An RGB color, written as a packed hexadecimal integer `0xRRGGBB`.
+///
+/// Requires the given integer literal to fit in 24 bits (`0x000000..=0xffffff`).
+///
+/// See [HexColor](super::HexColor) for a color written as a `"#rrggbb"` string instead.
+///
+/// ```rust
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::RgbColor;
+///
+/// #[overloaded_literals]
+/// pub fn correct() {
+///    let orange: RgbColor = 0xff8800;
+///    assert_eq!((orange.r(), orange.g(), orange.b()), (0xff, 0x88, 0x00));
+///
+///    let white: RgbColor = 0xffffff;
+///    assert_eq!(white.rgb(), (255, 255, 255));
+///    assert_eq!(white.packed(), 0xffffff);
+/// }
+/// correct()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::RgbColor;
+///
+/// #[overloaded_literals]
+/// pub fn oops_more_than_24_bits() {
+///    let color: RgbColor = 0x1000000;
+/// }
+/// oops_more_than_24_bits()
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct RgbColor {
+    r: u8,
+    g: u8,
+    b: u8,
+}
+
+impl RgbColor {
+    pub fn r(&self) -> u8 {
+        self.r
+    }
+
+    pub fn g(&self) -> u8 {
+        self.g
+    }
+
+    pub fn b(&self) -> u8 {
+        self.b
+    }
+
+    pub fn rgb(&self) -> (u8, u8, u8) {
+        (self.r, self.g, self.b)
+    }
+
+    /// The color packed back into an integer `0xRRGGBB`.
+    pub fn packed(&self) -> u32 {
+        u32::from_be_bytes([0, self.r, self.g, self.b])
+    }
+}
+
+impl<const LIT: u128> FromLiteralUnsigned<LIT> for RgbColor {
+    const VALID_LITERAL: u128 = {
+        assert!(LIT <= 0xffffff, "RgbColor has to fit in 24 bits (0xRRGGBB)");
+        LIT
+    };
+
+    #[inline]
+    fn into_self() -> Self {
+        let packed = <Self as FromLiteralUnsigned<LIT>>::VALID_LITERAL as u32;
+        let [_, r, g, b] = packed.to_be_bytes();
+        RgbColor { r, g, b }
+    }
+}
@@ -0,0 +1,90 @@
+use crate::FromLiteralUnsigned;
+use core::alloc::Layout;
+
+/// A size in bytes which is a multiple of the alignment `ALIGN`,
+/// following the same rules as [Layout].
+///
+/// Requires:
+/// - `ALIGN` to be a power of two
+/// - the given integer literal to be a multiple of `ALIGN`
+/// - the given integer literal to be at most `isize::MAX`
+///
+/// ```rust
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::SizeAlign;
+///
+/// #[overloaded_literals]
+/// pub fn correct() {
+///    let size: SizeAlign<8> = 64;
+///    assert_eq!(size.size(), 64);
+///    assert_eq!(size.layout().align(), 8);
+///
+///    let empty: SizeAlign<4096> = 0;
+///    assert_eq!(empty.size(), 0);
+/// }
+/// correct()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::SizeAlign;
+///
+/// #[overloaded_literals]
+/// pub fn oops_not_a_multiple() {
+///    let size: SizeAlign<8> = 12;
+/// }
+/// oops_not_a_multiple()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::SizeAlign;
+///
+/// #[overloaded_literals]
+/// pub fn oops_alignment_not_a_power_of_two() {
+///    let size: SizeAlign<12> = 24;
+/// }
+/// oops_alignment_not_a_power_of_two()
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SizeAlign<const ALIGN: usize>(usize);
+
+impl<const ALIGN: usize> SizeAlign<ALIGN> {
+    pub fn size(&self) -> usize {
+        self.0
+    }
+
+    pub fn align(&self) -> usize {
+        ALIGN
+    }
+
+    pub fn layout(&self) -> Layout {
+        // SAFETY: Alignment and size were checked at compile time
+        unsafe { Layout::from_size_align_unchecked(self.0, ALIGN) }
+    }
+}
+
+impl<const ALIGN: usize, const LIT: u128> FromLiteralUnsigned<LIT> for SizeAlign<ALIGN> {
+    // `u128::is_multiple_of` is only available since Rust 1.87
+    #[allow(unknown_lints, clippy::manual_is_multiple_of)]
+    const VALID_LITERAL: u128 = {
+        assert!(
+            ALIGN.is_power_of_two(),
+            "SizeAlign alignment has to be a power of two"
+        );
+        assert!(
+            LIT % (ALIGN as u128) == 0,
+            "SizeAlign size has to be a multiple of its alignment"
+        );
+        assert!(
+            LIT <= isize::MAX as u128,
+            "SizeAlign size cannot be larger than isize::MAX"
+        );
+        LIT
+    };
+
+    #[inline]
+    fn into_self() -> Self {
+        SizeAlign(<Self as FromLiteralUnsigned<LIT>>::VALID_LITERAL as usize)
+    }
+}
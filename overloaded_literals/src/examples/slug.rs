@@ -0,0 +1,97 @@
+use crate::{FromLiteralStr, TypeStr};
+
+/// A URL slug, like `"my-first-post"`.
+///
+/// Requires the given string literal to:
+/// - be non-empty
+/// - only contain lowercase ASCII letters, digits and hyphens (`-`)
+/// - not start or end with a hyphen
+/// - not contain two consecutive hyphens
+///
+/// ```rust
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::Slug;
+///
+/// #[overloaded_literals]
+/// pub fn correct() {
+///    let slug: Slug = "my-post";
+///    let numbered: Slug = "2023-release-notes";
+///    assert_eq!(slug.as_str(), "my-post");
+///    assert_eq!(numbered.as_str(), "2023-release-notes");
+/// }
+/// correct()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::Slug;
+///
+/// #[overloaded_literals]
+/// pub fn oops_uppercase_and_space() {
+///    let slug: Slug = "My Post";
+/// }
+/// oops_uppercase_and_space()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::Slug;
+///
+/// #[overloaded_literals]
+/// pub fn oops_trailing_hyphen() {
+///    let slug: Slug = "my-post-";
+/// }
+/// oops_trailing_hyphen()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::Slug;
+///
+/// #[overloaded_literals]
+/// pub fn oops_double_hyphen() {
+///    let slug: Slug = "my--post";
+/// }
+/// oops_double_hyphen()
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Slug(&'static str);
+
+impl Slug {
+    pub fn as_str(&self) -> &'static str {
+        self.0
+    }
+}
+
+const fn is_slug_byte(byte: u8) -> bool {
+    byte.is_ascii_lowercase() || byte.is_ascii_digit() || byte == b'-'
+}
+
+impl<Str: TypeStr> FromLiteralStr<Str> for Slug {
+    const VALID_LITERAL: &'static str = {
+        let bytes = Str::STR.as_bytes();
+        assert!(!bytes.is_empty(), "Slug cannot be empty");
+        let mut index = 0;
+        while index < bytes.len() {
+            assert!(
+                is_slug_byte(bytes[index]),
+                "Slug can only contain lowercase ASCII letters, digits and hyphens"
+            );
+            assert!(
+                !(bytes[index] == b'-' && index > 0 && bytes[index - 1] == b'-'),
+                "Slug cannot contain consecutive hyphens"
+            );
+            index += 1;
+        }
+        assert!(
+            bytes[0] != b'-' && bytes[bytes.len() - 1] != b'-',
+            "Slug cannot start or end with a hyphen"
+        );
+        Str::STR
+    };
+
+    #[inline]
+    fn into_self() -> Self {
+        Slug(<Self as FromLiteralStr<Str>>::VALID_LITERAL)
+    }
+}
@@ -0,0 +1,129 @@
+use crate::type_str::const_str_eq_ignore_ascii_case;
+use crate::{ConstFromLiteralStr, FromLiteralStr, TypeStr};
+
+/// An SQL identifier (like a table or column name) which is safe to use unquoted.
+///
+/// Requires the given string literal to:
+/// - start with an ASCII letter or an underscore
+/// - only contain ASCII letters, digits and underscores after that
+/// - not be one of the (case-insensitive) [reserved words](SqlIdent::RESERVED_WORDS)
+///
+/// ```rust
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::SqlIdent;
+///
+/// #[overloaded_literals]
+/// pub fn correct() {
+///    let table: SqlIdent = "users";
+///    let column: SqlIdent = "_created_at2";
+///    assert_eq!(table.as_str(), "users");
+///    assert_eq!(column.as_str(), "_created_at2");
+/// }
+/// correct()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::SqlIdent;
+///
+/// #[overloaded_literals]
+/// pub fn oops_reserved_word() {
+///    let table: SqlIdent = "select";
+/// }
+/// oops_reserved_word()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::SqlIdent;
+///
+/// #[overloaded_literals]
+/// pub fn oops_reserved_word_uppercase() {
+///    let table: SqlIdent = "SELECT";
+/// }
+/// oops_reserved_word_uppercase()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::SqlIdent;
+///
+/// #[overloaded_literals]
+/// pub fn oops_starts_with_digit() {
+///    let table: SqlIdent = "1users";
+/// }
+/// oops_starts_with_digit()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::SqlIdent;
+///
+/// #[overloaded_literals]
+/// pub fn oops_injection() {
+///    let table: SqlIdent = "users; DROP TABLE users";
+/// }
+/// oops_injection()
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SqlIdent(&'static str);
+
+impl SqlIdent {
+    /// The words which are rejected as identifiers because they have a special meaning in SQL.
+    ///
+    /// This is a small selection of the most common keywords,
+    /// rather than the full list of any particular SQL dialect.
+    pub const RESERVED_WORDS: &'static [&'static str] = &[
+        "all", "alter", "and", "as", "by", "create", "delete", "distinct", "drop", "from", "group",
+        "having", "in", "insert", "into", "join", "limit", "not", "null", "on", "or", "order",
+        "select", "set", "table", "union", "update", "values", "where",
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        self.0
+    }
+}
+
+const fn is_reserved_word(ident: &str) -> bool {
+    let mut index = 0;
+    while index < SqlIdent::RESERVED_WORDS.len() {
+        if const_str_eq_ignore_ascii_case(ident, SqlIdent::RESERVED_WORDS[index]) {
+            return true;
+        }
+        index += 1;
+    }
+    false
+}
+
+impl<Str: TypeStr> FromLiteralStr<Str> for SqlIdent {
+    const VALID_LITERAL: &'static str = {
+        let bytes = Str::STR.as_bytes();
+        assert!(!bytes.is_empty(), "SqlIdent cannot be empty");
+        assert!(
+            bytes[0].is_ascii_alphabetic() || bytes[0] == b'_',
+            "SqlIdent has to start with an ASCII letter or an underscore"
+        );
+        let mut index = 1;
+        while index < bytes.len() {
+            assert!(
+                bytes[index].is_ascii_alphanumeric() || bytes[index] == b'_',
+                "SqlIdent can only contain ASCII letters, digits and underscores"
+            );
+            index += 1;
+        }
+        assert!(
+            !is_reserved_word(Str::STR),
+            "SqlIdent cannot be a reserved SQL word"
+        );
+        Str::STR
+    };
+
+    #[inline]
+    fn into_self() -> Self {
+        <Self as ConstFromLiteralStr<Str>>::VALUE
+    }
+}
+
+impl<Str: TypeStr> ConstFromLiteralStr<Str> for SqlIdent {
+    const VALUE: Self = SqlIdent(<Self as FromLiteralStr<Str>>::VALID_LITERAL);
+}
@@ -0,0 +1,122 @@
+use crate::{ConstFromLiteralStr, FromLiteralStr, TypeStr};
+
+/// A time of day with minute precision, written as `"HH:MM"` in 24-hour notation.
+///
+/// Requires the given string literal to:
+/// - consist of exactly two digits for the hour, a colon, and two digits for the minutes
+/// - have an hour in the range `00..=23`
+/// - have a minute in the range `00..=59`
+///
+/// The literal is parsed only once, at compile time, into its [ConstFromLiteralStr] value,
+/// so it can be used in `const` and `static` items as well.
+///
+/// ```rust
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::TimeOfDay;
+///
+/// #[overloaded_literals]
+/// pub fn correct() {
+///    let time: TimeOfDay = "23:59";
+///    assert_eq!(time.hour(), 23);
+///    assert_eq!(time.minute(), 59);
+///
+///    let midnight: TimeOfDay = "00:00";
+///    assert_eq!(midnight, TimeOfDay::MIDNIGHT);
+///
+///    const NOON: TimeOfDay = "12:00";
+///    assert_eq!(NOON.hour(), 12);
+/// }
+/// correct()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::TimeOfDay;
+///
+/// #[overloaded_literals]
+/// pub fn oops_hour_out_of_range() {
+///    let time: TimeOfDay = "24:00";
+/// }
+/// oops_hour_out_of_range()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::TimeOfDay;
+///
+/// #[overloaded_literals]
+/// pub fn oops_minute_out_of_range() {
+///    let time: TimeOfDay = "12:60";
+/// }
+/// oops_minute_out_of_range()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use overloaded_literals::examples::TimeOfDay;
+///
+/// #[overloaded_literals]
+/// pub fn oops_missing_leading_zero() {
+///    let time: TimeOfDay = "9:30";
+/// }
+/// oops_missing_leading_zero()
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct TimeOfDay {
+    hour: u8,
+    minute: u8,
+}
+
+impl TimeOfDay {
+    pub const MIDNIGHT: TimeOfDay = TimeOfDay { hour: 0, minute: 0 };
+
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    pub fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    /// Minutes elapsed since midnight.
+    pub fn minutes_since_midnight(&self) -> u16 {
+        self.hour as u16 * 60 + self.minute as u16
+    }
+}
+
+const fn parse_digit(byte: u8) -> u8 {
+    assert!(
+        byte.is_ascii_digit(),
+        "TimeOfDay must be written as `HH:MM`"
+    );
+    byte - b'0'
+}
+
+const fn parse_time_of_day(literal: &str) -> TimeOfDay {
+    let bytes = literal.as_bytes();
+    assert!(
+        bytes.len() == 5 && bytes[2] == b':',
+        "TimeOfDay must be written as `HH:MM`"
+    );
+    let hour = parse_digit(bytes[0]) * 10 + parse_digit(bytes[1]);
+    let minute = parse_digit(bytes[3]) * 10 + parse_digit(bytes[4]);
+    assert!(hour <= 23, "TimeOfDay hour out of range (00..=23)");
+    assert!(minute <= 59, "TimeOfDay minute out of range (00..=59)");
+    TimeOfDay { hour, minute }
+}
+
+impl<Str: TypeStr> FromLiteralStr<Str> for TimeOfDay {
+    const VALID_LITERAL: &'static str = {
+        parse_time_of_day(Str::STR);
+        Str::STR
+    };
+
+    #[inline]
+    fn into_self() -> Self {
+        <Self as ConstFromLiteralStr<Str>>::VALUE
+    }
+}
+
+impl<Str: TypeStr> ConstFromLiteralStr<Str> for TimeOfDay {
+    const VALUE: Self = parse_time_of_day(<Self as FromLiteralStr<Str>>::VALID_LITERAL);
+}
@@ -1,19 +1,36 @@
 #![doc = include_str!("../README.md")]
 #![no_std]
 
-#[cfg(test)]
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(any(test, feature = "std"))]
 extern crate std;
 use core::ffi::CStr;
 #[cfg(test)]
 use std::println;
 
 extern crate self as overloaded_literals;
+mod const_from_literal;
+mod const_literal;
+mod error;
+pub mod examples;
+mod literal_debug;
+mod literal_map;
+mod try_from_value;
 pub mod type_float;
 pub mod type_str;
+mod validated;
 
+pub use const_from_literal::{
+    ConstFromLiteralSigned, ConstFromLiteralStr, ConstFromLiteralUnsigned,
+};
+pub use error::LiteralError;
+pub use literal_debug::{LiteralDebug, SourceLiteral};
+pub use try_from_value::TryFromLiteralValue;
 pub use type_float::TypeFloat;
-pub use type_str::TypeStr;
+pub use type_str::{TypeByteStr, TypeStr};
 
+use core::cmp::Ordering;
 use core::num::Wrapping;
 use core::num::{NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize};
 use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
@@ -38,8 +55,437 @@ use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZ
 /// - Any *unsigned* integer literal like `1337` is rewritten to [`FromLiteralUnsigned::<1337>::into_self()`](FromLiteralUnsigned)
 /// - Any *signed* integer literal like `-4200` is rewritten to [`FromLiteralSigned::<-4200>::into_self()`](FromLiteralSigned)
 /// - Any `str` literal like `"hello"` is rewritten to [`FromLiteralStr::<"hello">::into_self()`](FromLiteralStr)
+/// - Any byte string literal like `b"hello"` is rewritten to [`FromLiteralByteStr::<b"hello">::into_self()`](FromLiteralByteStr)
+/// - Any `char` literal like `'a'` is rewritten to [`FromLiteralChar::<'a'>::into_self()`](FromLiteralChar)
+/// - Any byte literal like `b'A'` is rewritten to [`FromLiteralByte::<b'A'>::into_self()`](FromLiteralByte)
+///
+/// Literals with an explicit type suffix, like `42i32`, `7u8` or `1.5f64`, are left alone:
+/// the suffix already says that a plain primitive is wanted.
+/// This doubles as an escape hatch: `let n = 42u32;` is a plain `u32`,
+/// whereas `let n: u32 = 42;` goes through [FromLiteralUnsigned] (and ends up as the same `u32`).
+///
+/// Literals in the condition of a `while` loop and in the iterator of a `for` loop are left alone too,
+/// since loop bounds like those in `for i in 0..10` usually have to stay plain integers (often `usize`, for indexing).
+/// The same goes for indices, like the `0` in `arr[0]` or the range in `values[1..]`.
+/// (The indexed expression itself is rewritten as usual, so the elements in `&[1, 2, 3][..]` are.)
+/// Similarly, a `true` or `false` used directly as the condition of an `if` or a match guard
+/// (possibly combined with `!`, `&&` and `||`) is a plain `bool` and is left alone.
+/// (Other literals in those conditions, like the `5` in `if x < 5`, are rewritten as usual.)
+///
+/// Mark a literal in these places with `#[overload]` to rewrite it anyway, like in `while tries < #[overload] 3`.
+/// The marker is removed by the macro. It can be used on any literal, but elsewhere it makes no difference.
+///
+/// Conversely, mark a statement or expression with `#[overloaded_literals::skip]` to leave the literals in it alone,
+/// like in `#[overloaded_literals::skip] let raw: u64 = 0;`.
+/// On a block, this applies to everything inside of it.
+/// It can also be put on a macro invocation statement like `println!("{}", 1);`, or on a nested item like a function.
+/// The innermost marker wins: a literal marked with `#[overload]` inside a skipped block or statement is still rewritten.
+/// (On a nested function, `#[overloaded_literals(skip)]` works as well, see [Nested items](#nested-items).)
+///
+/// When the type of the literal is known from a `let` type annotation, it is named explicitly:
+/// `let x: NonZeroU8 = 10;` is rewritten to `let x: NonZeroU8 = <NonZeroU8 as FromLiteralUnsigned<10>>::into_self();`.
+/// This means the implementation for that type is used even in cases where inference would otherwise get stuck,
+/// and a type which does not implement the trait results in a clear 'trait bound not satisfied' error.
+/// The annotated type is also passed on to the tail expressions of blocks and the branches of `if` and `match` expressions,
+/// so in `let x: NonZeroU8 = if c { 1 } else { 2 };` both literals are rewritten this way.
+/// Similarly, the elements of a tuple are matched up with the element types of an annotated tuple type,
+/// as in `let t: (NonZeroU8, &str) = (5, "hello");`.
+/// Each element of an array gets the element type of an annotated array type, as in `let xs: [NonZeroU8; 3] = [1, 2, 3];`,
+/// and so does the repeated element of `let buf: [NonZeroU8; 4] = [1; 4];` (the length `4` is always left alone).
+/// The same goes for the elements of a `vec!` with an annotated `Vec<T>` type,
+/// as in `let xs: Vec<NonZeroU8> = vec![1, 2, 3];` or `let buf: Vec<NonZeroU8> = vec![1; 4];`.
+/// This is the only macro invocation which is rewritten without the `macros` argument (see [Arguments](#arguments)),
+/// since its tokens are known to be an array expression.
+/// The return type of a function is used in the same way for its tail expression and for the values of `return` expressions,
+/// as in `fn port(fallback: bool) -> Port { if fallback { return 8080; } 80 }`,
+/// and so is the return type annotation of a closure, as in `|x: u8| -> NonZeroU8 { 5 }`.
+/// (A return type mentioning `impl Trait` cannot be named, so there type inference is relied upon instead.)
+///
+/// Without an annotation, the type of a literal can only be inferred from how it is used later on.
+/// For instance, for a literal passed as a function or method argument, as in `retries(3)` or `server.listen(8080)`,
+/// it is inferred from the type of the parameter.
+/// Likewise, a literal used as a struct field, as in `Job { retries: 5 }`, is inferred from the declared type of the field.
+/// For a tuple like `let t = (5, "hello");` which is only ever passed to generic code (like `println!("{t:?}")`),
+/// there is nothing to infer the types from, and compilation fails with a 'type annotations needed' error.
+///
+/// # Arguments
+///
+/// - `int`, `uint`, `sint`, `str`, `bytestr`, `float`, `bool`, `char` and `byte`: Only rewrite literals of the listed kinds,
+///   leaving all other literals as they are.
+///   `uint` stands for positive integer literals ([FromLiteralUnsigned](trait@FromLiteralUnsigned)),
+///   `sint` for negative ones ([FromLiteralSigned](trait@FromLiteralSigned)) and `int` for both.
+///   For instance, `#[overloaded_literals(int, str)]` leaves float, bool, char and byte literals alone.
+///   Without any of these, literals of all kinds are rewritten.
+/// - `macros`: Also rewrite literals in the arguments of macro invocations.
+///   Because a macro can accept arbitrary tokens, this is only done for a fixed set of macros
+///   whose arguments are known to be plain expressions:
+///   - `dbg!`
+///   - `format!`, `format_args!`, `print!`, `println!`, `eprint!` and `eprintln!`:
+///     the format string is left alone and the arguments after it are rewritten.
+///   - `write!` and `writeln!`: the destination and the arguments after the format string are rewritten.
+///   - `vec!`: the elements (or the repeated element of `vec![x; n]`, but not the length `n`) are rewritten,
+///     also when there is no `Vec<T>` annotation to take the element type from.
+///
+///   Other macro invocations are always left alone.
+///   This includes macros which accept a format string in some other position (like `assert!`),
+///   and custom macros which forward to `format_args!`.
+///
+///   Note that the formatting macros accept arguments of any type implementing `Display`/`Debug`.
+///   So for a rewritten literal argument like the `5` in `println!("{}", 5)`,
+///   type inference has nothing to go on, and compilation fails.
+///   Use `skip_macros(println)` (or a typed binding) in that case.
+/// - `skip_macros(name, ...)`: Leave the listed macros alone, even when `macros` is enabled.
+///   For instance, `#[overloaded_literals(macros, skip_macros(dbg))]`.
+///   Use `skip_macros(vec)` to leave even a `vec!` with a `Vec<T>` annotation alone.
+/// - `skip`: Leave the item alone entirely.
+///   Only useful on an item inside an annotated module or function (see [Modules](#modules-and-impl-blocks) and [Nested items](#nested-items)).
+///
+/// ```rust
+/// use overloaded_literals::overloaded_literals;
+/// use std::num::NonZeroU8;
+///
+/// #[overloaded_literals(macros)]
+/// fn example() -> NonZeroU8 {
+///     dbg!(5)
+/// }
+/// assert_eq!(example().get(), 5);
+/// ```
+///
+/// # Modules and `impl` blocks
+///
+/// The attribute can also be put on an inline module, to rewrite the literals of
+/// all functions and methods in it (including those in nested inline modules) using the same arguments.
+/// Literals in `const`s and `static`s in the module are rewritten as described in [`const` and `static` items](#const-and-static-items).
+///
+/// A function, method, `impl` block or nested module inside which has an `#[overloaded_literals(...)]` attribute of its own
+/// is only rewritten according to that attribute: its arguments replace those of the module rather than adding to them.
+///
+/// ```rust
+/// #[overloaded_literals::overloaded_literals(int)]
+/// mod numbers {
+///     use overloaded_literals::{examples::SqlIdent, overloaded_literals};
+///     use std::num::NonZeroU8;
+///
+///     pub fn five() -> NonZeroU8 {
+///         5
+///     }
+///
+///     #[overloaded_literals(str)]
+///     pub fn table() -> SqlIdent {
+///         "users"
+///     }
+/// }
+/// assert_eq!(numbers::five().get(), 5);
+/// assert_eq!(numbers::table().as_str(), "users");
+/// ```
+///
+/// Similarly, on an `impl` block the attribute rewrites the literals of all of its methods (with the same precedence),
+/// and those of its associated `const`s like those of other `const` items.
+///
+/// ```rust
+/// use core::num::NonZeroU8;
+/// use overloaded_literals::{examples::{Port, SqlIdent}, overloaded_literals};
+///
+/// struct Config;
+///
+/// #[overloaded_literals]
+/// impl Config {
+///     const VERSION: u32 = 2;
+///     const RETRIES: NonZeroU8 = 3;
+///
+///     fn port(&self) -> Port {
+///         8080
+///     }
+///
+///     fn table(&self) -> SqlIdent {
+///         "users"
+///     }
+/// }
+/// assert_eq!(Config.port().get(), 8080);
+/// assert_eq!(Config.table().as_str(), "users");
+/// assert_eq!(Config::VERSION, 2);
+/// assert_eq!(Config::RETRIES.get(), 3);
+/// ```
+///
+/// # Nested items
+///
+/// Functions, `impl` blocks and modules defined inside the body of an annotated function are rewritten too.
+/// The same precedence applies as for modules: one with an `#[overloaded_literals(...)]` attribute of its own
+/// is only rewritten according to that attribute. Use `#[overloaded_literals(skip)]` to exclude one altogether.
+///
+/// ```rust
+/// use overloaded_literals::{examples::SqlIdent, overloaded_literals};
+///
+/// #[overloaded_literals]
+/// fn example() {
+///     fn table() -> SqlIdent {
+///         "users"
+///     }
+///
+///     #[overloaded_literals(skip)]
+///     fn big() -> u64 {
+///         1 << 40
+///     }
+///
+///     assert_eq!(table().as_str(), "users");
+///     assert_eq!(big(), 1 << 40);
+/// }
+/// example()
+/// ```
+///
+/// # `const` and `static` items
+///
+/// `into_self()` cannot be called in the initializer of a `const` or `static` item, since it is not a `const fn`.
+/// So in `const` and `static` items inside the body of an annotated function or in an annotated module,
+/// and in the associated `const`s of an annotated `impl` block,
+/// integer and string literals of the item's type (like the `10` in `const MAX: NonZeroU8 = 10;`)
+/// are rewritten to the `VALUE` of [ConstFromLiteralUnsigned], [ConstFromLiteralSigned] or [ConstFromLiteralStr] instead.
+/// Any other literals there (including those whose type is not known, like both in `const N: usize = 10 * 4;`) are left alone.
+///
+/// ```rust
+/// use core::num::NonZeroU8;
+/// use overloaded_literals::{examples::SqlIdent, overloaded_literals};
+///
+/// #[overloaded_literals]
+/// fn example() {
+///     const MAX: NonZeroU8 = 10;
+///     static TABLE: SqlIdent = "users";
+///     const BYTES: usize = 10 * 4;
+///     assert_eq!(MAX.get(), 10);
+///     assert_eq!(TABLE.as_str(), "users");
+///     assert_eq!(BYTES, 40);
+/// }
+/// example()
+/// ```
 pub use overloaded_literals_macro::overloaded_literals;
 
+/// Derive macro to implement [FromLiteralUnsigned](trait@FromLiteralUnsigned) for an enum whose variants have no fields,
+/// or for a tuple struct with a single field.
+///
+/// For an enum, each variant is constructed from the integer literal which equals its discriminant.
+/// Any other literal results in a compile error.
+///
+/// ```rust
+/// use overloaded_literals::{overloaded_literals, FromLiteralUnsigned};
+///
+/// #[derive(Debug, PartialEq, Eq, FromLiteralUnsigned)]
+/// #[repr(u8)]
+/// pub enum Opcode {
+///     Nop = 0,
+///     Add = 1,
+///     Sub = 2,
+///     Halt = 0xff,
+/// }
+///
+/// #[overloaded_literals]
+/// fn example() {
+///     let op: Opcode = 1;
+///     assert_eq!(op, Opcode::Add);
+///     let op: Opcode = 255;
+///     assert_eq!(op, Opcode::Halt);
+/// }
+/// example()
+/// ```
+///
+/// ```compile_fail
+/// use overloaded_literals::{overloaded_literals, FromLiteralUnsigned};
+///
+/// #[derive(Debug, PartialEq, Eq, FromLiteralUnsigned)]
+/// #[repr(u8)]
+/// pub enum Opcode {
+///     Nop = 0,
+///     Add = 1,
+///     Sub = 2,
+///     Halt = 0xff,
+/// }
+///
+/// #[overloaded_literals]
+/// fn oops_unknown_discriminant() {
+///     let op: Opcode = 3;
+/// }
+/// oops_unknown_discriminant()
+/// ```
+///
+/// For a tuple struct like `Percentage(u8)`, the literal is checked against the `#[range(...)]` attribute
+/// (like `#[range(0..=100)]`, `#[range(1..)]` or `#[range(..1000)]`),
+/// and then used to build the field.
+/// So it also has to be accepted by the type of the field: for a `u8` field, it cannot be larger than 255.
+/// Without a `#[range(...)]` attribute, any literal accepted by the type of the field is.
+/// Use [derive@FromLiteralSigned] as well to accept negative literals.
+///
+/// Both for enums and for tuple structs, [TryFromLiteralValue]`<u128>` is implemented as well,
+/// performing the same checks at runtime.
+/// For a tuple struct, this requires the type of the field to implement it too.
+///
+/// ```rust
+/// use overloaded_literals::{overloaded_literals, FromLiteralUnsigned};
+///
+/// #[derive(Debug, PartialEq, Eq, FromLiteralUnsigned)]
+/// #[range(0..=100)]
+/// pub struct Percentage(u8);
+///
+/// #[overloaded_literals]
+/// fn example() {
+///     let half: Percentage = 50;
+///     assert_eq!(half, Percentage(50));
+///     let full: Percentage = 100;
+///     assert_eq!(full, Percentage(100));
+/// }
+/// example()
+/// ```
+///
+/// ```compile_fail
+/// use overloaded_literals::{overloaded_literals, FromLiteralUnsigned};
+///
+/// #[derive(Debug, PartialEq, Eq, FromLiteralUnsigned)]
+/// #[range(0..=100)]
+/// pub struct Percentage(u8);
+///
+/// #[overloaded_literals]
+/// fn oops_out_of_range() {
+///     let too_much: Percentage = 101;
+/// }
+/// oops_out_of_range()
+/// ```
+pub use overloaded_literals_macro::FromLiteralUnsigned;
+
+/// Derive macro to implement [FromLiteralSigned](trait@FromLiteralSigned) for a tuple struct with a single field.
+///
+/// Just like for [derive@FromLiteralUnsigned], the literal is checked against the `#[range(...)]` attribute (if any),
+/// and then used to build the field.
+/// Derive both to accept positive as well as negative literals.
+///
+/// [TryFromLiteralValue]`<i128>` is implemented as well, just like [TryFromLiteralValue]`<u128>` is by [derive@FromLiteralUnsigned].
+///
+/// ```rust
+/// use overloaded_literals::{overloaded_literals, FromLiteralSigned, FromLiteralUnsigned};
+///
+/// /// A temperature outdoors, in degrees Celsius.
+/// #[derive(Debug, PartialEq, Eq, FromLiteralUnsigned, FromLiteralSigned)]
+/// #[range(-90..=60)]
+/// pub struct Celsius(i16);
+///
+/// #[overloaded_literals]
+/// fn example() {
+///     let freezing: Celsius = -10;
+///     assert_eq!(freezing, Celsius(-10));
+///     let lowest: Celsius = -90;
+///     assert_eq!(lowest, Celsius(-90));
+/// }
+/// example()
+/// ```
+///
+/// ```compile_fail
+/// use overloaded_literals::{overloaded_literals, FromLiteralSigned, FromLiteralUnsigned};
+///
+/// #[derive(Debug, PartialEq, Eq, FromLiteralUnsigned, FromLiteralSigned)]
+/// #[range(-90..=60)]
+/// pub struct Celsius(i16);
+///
+/// #[overloaded_literals]
+/// fn oops_too_cold() {
+///     let unheard_of: Celsius = -100;
+/// }
+/// oops_too_cold()
+/// ```
+pub use overloaded_literals_macro::FromLiteralSigned;
+
+/// Derive macro to implement [FromLiteralStr](trait@FromLiteralStr) for an enum whose variants have no fields.
+///
+/// Each variant is constructed from the string literal(s) given in its `#[literal(...)]` attribute.
+/// Any other literal results in a compile error.
+///
+/// [TryFromLiteralValue] is implemented for any `&str` as well, to look up a string which is only known at runtime.
+///
+/// ```rust
+/// use overloaded_literals::{overloaded_literals, FromLiteralStr};
+///
+/// #[derive(Debug, PartialEq, Eq, FromLiteralStr)]
+/// pub enum Greeting {
+///     #[literal("hello", "hi")]
+///     Hello,
+///     #[literal("goodbye")]
+///     Goodbye,
+/// }
+///
+/// #[overloaded_literals]
+/// fn example() {
+///     let greeting: Greeting = "hello";
+///     assert_eq!(greeting, Greeting::Hello);
+///     let greeting: Greeting = "hi";
+///     assert_eq!(greeting, Greeting::Hello);
+///     let greeting: Greeting = "goodbye";
+///     assert_eq!(greeting, Greeting::Goodbye);
+/// }
+/// example();
+///
+/// use overloaded_literals::TryFromLiteralValue;
+/// let input = String::from("hi");
+/// assert_eq!(Greeting::try_from_value(input.as_str()), Ok(Greeting::Hello));
+/// ```
+///
+/// ```compile_fail
+/// use overloaded_literals::{overloaded_literals, FromLiteralStr};
+///
+/// #[derive(Debug, PartialEq, Eq, FromLiteralStr)]
+/// pub enum Greeting {
+///     #[literal("hello", "hi")]
+///     Hello,
+///     #[literal("goodbye")]
+///     Goodbye,
+/// }
+///
+/// #[overloaded_literals]
+/// fn oops_unknown_greeting() {
+///     let greeting: Greeting = "hehehehehe";
+/// }
+/// oops_unknown_greeting()
+/// ```
+pub use overloaded_literals_macro::FromLiteralStr;
+
+/// Re-export of the [const-str](https://crates.io/crates/const-str) crate.
+///
+/// Only available when the `const-str` feature is enabled.
+///
+/// Since not many operations on `&'static str` are stably allowed in const contexts yet,
+/// its macros are very useful when implementing [FromLiteralStr::VALID_LITERAL].
+/// Using this re-export means you do not have to add (and keep in sync) a dependency on `const-str` yourself.
+/// Like the rest of this crate, it works in `no_std` contexts.
+///
+/// Note that only the macros which are evaluated as a normal (const) expression
+/// can be used with the generic `TStr::STR`,
+/// such as `equal!`, `compare!`, `eq_ignore_ascii_case!`, `contains!`, `starts_with!`, `ends_with!`, `strip_prefix!` and `strip_suffix!`.
+/// Macros like `replace!` or `concat!` which build a new string internally define a `const` item,
+/// and a `const` item cannot refer to the generic parameters of its surrounding impl.
+///
+/// ```rust
+/// use overloaded_literals::{overloaded_literals, const_str, FromLiteralStr, TypeStr};
+///
+/// pub struct HttpsUrl(&'static str);
+///
+/// impl<Str: TypeStr> FromLiteralStr<Str> for HttpsUrl {
+///     const VALID_LITERAL: &'static str = {
+///         let val = Str::STR;
+///         assert!(const_str::starts_with!(val, "https://"), "HttpsUrl has to start with `https://`");
+///         assert!(!const_str::contains!(val, ' '), "HttpsUrl cannot contain spaces");
+///         val
+///     };
+///
+///     fn into_self() -> Self {
+///         HttpsUrl(<Self as FromLiteralStr<Str>>::VALID_LITERAL)
+///     }
+/// }
+///
+/// #[overloaded_literals]
+/// fn example() {
+///     let url: HttpsUrl = "https://example.com";
+///     // let oops: HttpsUrl = "http://example.com"; // <- This would cause a compile error :-)
+/// }
+/// example()
+/// ```
+#[cfg(feature = "const-str")]
+pub use const_str;
+
 mod sealed {
     pub trait Sealed {}
     impl<const VAL: u8> Sealed for crate::type_str::Byte<VAL> {}
@@ -78,6 +524,7 @@ mod sealed {
 /// As an example, consider a simple enum, whose valid values are `"hello"` and `"goodbye".`
 /// Since the amount of builtin const operations that are allowed on `str` is currently limited on stable rust,
 /// we use the [const_str](https://crates.io/crates/const-str) crate for these.
+/// (It is re-exported as `overloaded_literals::const_str` when the `const-str` feature is enabled.)
 /// ```rust
 /// use overloaded_literals::{overloaded_literals, FromLiteralStr, TypeStr};
 ///
@@ -114,6 +561,8 @@ mod sealed {
 /// example()
 /// ```
 ///
+/// For an enum like this, the [derive macro](derive@FromLiteralStr) writes this implementation for you.
+///
 /// Another good example is [the implementation for CStr](#impl-FromLiteralStr<TStr>-for-%26%27static%20CStr) which is included with the library.
 pub trait FromLiteralStr<TStr: TypeStr> {
     /// The definition of `VALID_LITERAL` is evaluated at compile-time.
@@ -126,7 +575,8 @@ pub trait FromLiteralStr<TStr: TypeStr> {
     ///   Because this is evaluated at compile-time, this results in a compile error.
     ///
     /// Since not many operations on `&'static str` are stably allowed in const contexts yet,
-    /// you might want to use crates like [const-str](https://crates.io/crates/const-str).
+    /// you might want to use crates like [const-str](https://crates.io/crates/const-str)
+    /// (which is re-exported when the `const-str` feature is enabled).
     const VALID_LITERAL: &'static str;
 
     /// Turns a [VALID_LITERAL](FromLiteralStr::VALID_LITERAL) into the actual runtime value.
@@ -138,11 +588,14 @@ pub trait FromLiteralStr<TStr: TypeStr> {
     ///
     /// If you want, you can use an unsafe 'unchecked' constructor, if one exists, since you have done any validation already.
     /// (But even if using a normal constructor, in all likelyhood the compiler is smart enough to remove the duplicate checks since the input is a literal value.)
+    ///
+    /// This method is `#[track_caller]`, so should it panic anyway, the panic points at the literal rather than at your implementation.
+    #[track_caller]
     fn into_self() -> Self;
 }
 
 // Base definition
-impl<'a, Str: TypeStr> FromLiteralStr<Str> for &'a str {
+impl<Str: TypeStr> FromLiteralStr<Str> for &str {
     const VALID_LITERAL: &'static str = Str::STR;
 
     #[inline]
@@ -151,25 +604,107 @@ impl<'a, Str: TypeStr> FromLiteralStr<Str> for &'a str {
     }
 }
 
+impl<Str: TypeStr> ConstFromLiteralStr<Str> for &str {
+    const VALUE: Self = <Self as FromLiteralStr<Str>>::VALID_LITERAL;
+}
+
+impl<'a> TryFromLiteralValue<&'a str> for &'a str {
+    #[inline]
+    fn try_from_value(value: &'a str) -> Result<Self, LiteralError> {
+        Ok(value)
+    }
+}
+
+/// The path is not validated, since there is little that could be checked at compile time in a portable way.
+/// If you want to restrict the paths which are accepted, implement [FromLiteralStr] for a newtype of your own,
+/// checking the literal in its `VALID_LITERAL`.
+#[cfg(feature = "std")]
+impl<Str: TypeStr> FromLiteralStr<Str> for std::path::PathBuf {
+    const VALID_LITERAL: &'static str = Str::STR;
+
+    #[inline]
+    fn into_self() -> Self {
+        std::path::PathBuf::from(<Self as FromLiteralStr<Str>>::VALID_LITERAL)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> TryFromLiteralValue<&'a str> for std::path::PathBuf {
+    #[inline]
+    fn try_from_value(value: &'a str) -> Result<Self, LiteralError> {
+        Ok(std::path::PathBuf::from(value))
+    }
+}
+
+/// Like the implementation for `PathBuf`, the path is not validated.
+#[cfg(feature = "std")]
+impl<Str: TypeStr> FromLiteralStr<Str> for &'static std::path::Path {
+    const VALID_LITERAL: &'static str = Str::STR;
+
+    #[inline]
+    fn into_self() -> Self {
+        std::path::Path::new(<Self as FromLiteralStr<Str>>::VALID_LITERAL)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> TryFromLiteralValue<&'a str> for &'a std::path::Path {
+    #[inline]
+    fn try_from_value(value: &'a str) -> Result<Self, LiteralError> {
+        Ok(std::path::Path::new(value))
+    }
+}
+
 // Build owned strings directly from string literals
-// impl<Str: TypeStr> FromLiteralStr<Str> for String {
-//     const VALID_LITERAL: &'static str = Str::STR;
-//     fn into_self() -> Self {
-//         <Self as FromLiteralStr<Str>>::VALID_LITERAL.to_string()
-//     }
-// }
+#[cfg(feature = "alloc")]
+impl<Str: TypeStr> FromLiteralStr<Str> for alloc::string::String {
+    const VALID_LITERAL: &'static str = Str::STR;
 
-/// Build your datatype from an unsigned integer literal (0, 1, 2, 3, ...).
+    #[inline]
+    fn into_self() -> Self {
+        alloc::string::String::from(<Self as FromLiteralStr<Str>>::VALID_LITERAL)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> TryFromLiteralValue<&'a str> for alloc::string::String {
+    #[inline]
+    fn try_from_value(value: &'a str) -> Result<Self, LiteralError> {
+        Ok(alloc::string::String::from(value))
+    }
+}
+
+/// Always `Cow::Borrowed`, so unlike the implementation for `String` this does not allocate.
+#[cfg(feature = "alloc")]
+impl<Str: TypeStr> FromLiteralStr<Str> for alloc::borrow::Cow<'static, str> {
+    const VALID_LITERAL: &'static str = Str::STR;
+
+    #[inline]
+    fn into_self() -> Self {
+        alloc::borrow::Cow::Borrowed(<Self as FromLiteralStr<Str>>::VALID_LITERAL)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> TryFromLiteralValue<&'a str> for alloc::borrow::Cow<'a, str> {
+    #[inline]
+    fn try_from_value(value: &'a str) -> Result<Self, LiteralError> {
+        Ok(alloc::borrow::Cow::Borrowed(value))
+    }
+}
+
+/// Build your datatype from a `&'static [u8]` byte string literal.
 ///
-/// The [macro@overloaded_literals] macro turns unsigned integer literals like
+/// The [macro@overloaded_literals] macro turns byte string literals like
 /// ```compile_only
-/// 1234
+/// b"\x00\xFF"
 /// ```
 /// into calls to
 ///
 /// ```compile_only
-/// FromLiteralStr::<1234>::VALID_LITERAL::into_self()
+/// FromLiteralByteStr::<b"\x00\xFF">::VALID_LITERAL::into_self()
 /// ```
+/// _NOTE: Just like for [FromLiteralStr], the real desugaring is slightly more involved, see [TypeByteStr] if you are curious._
 ///
 /// The first part (`VALID_LITERAL`) runs at compile-time, allowing you to perform input checks,
 /// where invalid input results in a compile error.
@@ -178,52 +713,169 @@ impl<'a, Str: TypeStr> FromLiteralStr<Str> for &'a str {
 /// knowing that the input is guaranteed to be valid.
 ///
 /// ```txt
-/// FromLiteralUnsigned::<1234>::VALID_LITERAL.into_self()
-/// ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
-///               compile time                 ^^^^^^^^^^^
-///                                              runtime
+/// FromLiteralByteStr::<b"\x00\xFF">::VALID_LITERAL.into_self()
+/// ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+///                 compile time                   ^^^^^^^^^^^
+///                                                  runtime
 /// ```
 ///
-pub trait FromLiteralUnsigned<const LIT: u128> {
+/// See [MacAddress](examples::MacAddress) for an example which requires a fixed number of bytes.
+pub trait FromLiteralByteStr<TBytes: TypeByteStr> {
     /// The definition of `VALID_LITERAL` is evaluated at compile-time.
     ///
-    /// Inside this definition you have access to `LIT`.
+    /// Inside this definition you have access to `TBytes::BYTES` which returns a `&'static [u8]`.
     ///
     /// An implementation of `VALID_LITERAL` should perform input checking:
-    /// - If the input is valid, return `LIT` unchanged.
+    /// - If the input is valid, return `TBytes::BYTES` unchanged.
     /// - If the input is invalid, [panic](core::panic!).
     ///   Because this is evaluated at compile-time, this results in a compile error.
-    const VALID_LITERAL: u128;
+    const VALID_LITERAL: &'static [u8];
 
-    /// Turns a [VALID_LITERAL](FromLiteralUnsigned::VALID_LITERAL) into the actual runtime value.
+    /// Turns a [VALID_LITERAL](FromLiteralByteStr::VALID_LITERAL) into the actual runtime value.
     ///
     /// This part runs at runtime.
     ///
-    /// You have access to [VALID_LITERAL](FromLiteralUnsigned::VALID_LITERAL) (using the syntax `let val = <Self as FromLiteralUnsigned<LIT>>::VALID_LITERAL;`),
+    /// You have access to [VALID_LITERAL](FromLiteralByteStr::VALID_LITERAL) (using the syntax `let val = <Self as FromLiteralByteStr<TBytes>>::VALID_LITERAL;`),
     /// and should turn it into your desired value.
     ///
     /// If you want, you can use an unsafe 'unchecked' constructor, if one exists, since you have done any validation already.
     /// (But even if using a normal constructor, in all likelyhood the compiler is smart enough to remove the duplicate checks since the input is a literal value.)
+    ///
+    /// This method is `#[track_caller]`, so should it panic anyway, the panic points at the literal rather than at your implementation.
+    #[track_caller]
     fn into_self() -> Self;
 }
 
-/// Build your datatype from a signed integer literal (-1, -2, -3, ...).
-///
-/// The [macro@overloaded_literals] macro turns signed integer literals like
-/// ```compile_only
-/// -1234
-/// ```
-/// into calls to
-///
-/// ```compile_only
-/// FromLiteralSigned::<-1234>::VALID_LITERAL::into_self()
-/// ```
-///
-/// **NOTE: In the common case where the target type also accepts 0 or positive integer literals, it should also implement [FromLiteralUnsigned].**
-///
-/// The first part (`VALID_LITERAL`) runs at compile-time, allowing you to perform input checks,
-/// where invalid input results in a compile error.
-///
+// Base definition
+impl<Bytes: TypeByteStr> FromLiteralByteStr<Bytes> for &[u8] {
+    const VALID_LITERAL: &'static [u8] = Bytes::BYTES;
+
+    #[inline]
+    fn into_self() -> Self {
+        <Self as FromLiteralByteStr<Bytes>>::VALID_LITERAL
+    }
+}
+
+impl<'a> TryFromLiteralValue<&'a [u8]> for &'a [u8] {
+    #[inline]
+    fn try_from_value(value: &'a [u8]) -> Result<Self, LiteralError> {
+        Ok(value)
+    }
+}
+
+// A byte string literal's own type, like `&[u8; 3]` for `b"abc"`
+impl<const N: usize, Bytes: TypeByteStr> FromLiteralByteStr<Bytes> for &[u8; N] {
+    const VALID_LITERAL: &'static [u8] = {
+        assert!(
+            Bytes::LEN == N,
+            "Byte string literal does not have the length of the array type"
+        );
+        Bytes::BYTES
+    };
+
+    #[inline]
+    fn into_self() -> Self {
+        match <Self as FromLiteralByteStr<Bytes>>::VALID_LITERAL.try_into() {
+            Ok(array) => array,
+            Err(_) => unreachable!("the length was checked at compile time"),
+        }
+    }
+}
+
+impl<'a, const N: usize> TryFromLiteralValue<&'a [u8]> for &'a [u8; N] {
+    #[inline]
+    fn try_from_value(value: &'a [u8]) -> Result<Self, LiteralError> {
+        value.try_into().map_err(|_| LiteralError::InvalidByteStr)
+    }
+}
+
+// Build owned byte buffers directly from byte string literals
+#[cfg(feature = "alloc")]
+impl<Bytes: TypeByteStr> FromLiteralByteStr<Bytes> for alloc::vec::Vec<u8> {
+    const VALID_LITERAL: &'static [u8] = Bytes::BYTES;
+
+    #[inline]
+    fn into_self() -> Self {
+        <Self as FromLiteralByteStr<Bytes>>::VALID_LITERAL.to_vec()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> TryFromLiteralValue<&'a [u8]> for alloc::vec::Vec<u8> {
+    #[inline]
+    fn try_from_value(value: &'a [u8]) -> Result<Self, LiteralError> {
+        Ok(value.to_vec())
+    }
+}
+
+/// Build your datatype from an unsigned integer literal (0, 1, 2, 3, ...).
+///
+/// The [macro@overloaded_literals] macro turns unsigned integer literals like
+/// ```compile_only
+/// 1234
+/// ```
+/// into calls to
+///
+/// ```compile_only
+/// FromLiteralStr::<1234>::VALID_LITERAL::into_self()
+/// ```
+///
+/// The first part (`VALID_LITERAL`) runs at compile-time, allowing you to perform input checks,
+/// where invalid input results in a compile error.
+///
+/// The second part (`into_self()`) runs at runtime, and is where you create your actual value,
+/// knowing that the input is guaranteed to be valid.
+///
+/// ```txt
+/// FromLiteralUnsigned::<1234>::VALID_LITERAL.into_self()
+/// ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+///               compile time                 ^^^^^^^^^^^
+///                                              runtime
+/// ```
+///
+pub trait FromLiteralUnsigned<const LIT: u128> {
+    /// The definition of `VALID_LITERAL` is evaluated at compile-time.
+    ///
+    /// Inside this definition you have access to `LIT`.
+    ///
+    /// An implementation of `VALID_LITERAL` should perform input checking:
+    /// - If the input is valid, return `LIT` unchanged.
+    /// - If the input is invalid, [panic](core::panic!).
+    ///   Because this is evaluated at compile-time, this results in a compile error.
+    const VALID_LITERAL: u128;
+
+    /// Turns a [VALID_LITERAL](FromLiteralUnsigned::VALID_LITERAL) into the actual runtime value.
+    ///
+    /// This part runs at runtime.
+    ///
+    /// You have access to [VALID_LITERAL](FromLiteralUnsigned::VALID_LITERAL) (using the syntax `let val = <Self as FromLiteralUnsigned<LIT>>::VALID_LITERAL;`),
+    /// and should turn it into your desired value.
+    ///
+    /// If you want, you can use an unsafe 'unchecked' constructor, if one exists, since you have done any validation already.
+    /// (But even if using a normal constructor, in all likelyhood the compiler is smart enough to remove the duplicate checks since the input is a literal value.)
+    ///
+    /// This method is `#[track_caller]`, so should it panic anyway, the panic points at the literal rather than at your implementation.
+    #[track_caller]
+    fn into_self() -> Self;
+}
+
+/// Build your datatype from a signed integer literal (-1, -2, -3, ...).
+///
+/// The [macro@overloaded_literals] macro turns signed integer literals like
+/// ```compile_only
+/// -1234
+/// ```
+/// into calls to
+///
+/// ```compile_only
+/// FromLiteralSigned::<-1234>::VALID_LITERAL::into_self()
+/// ```
+///
+/// **NOTE: In the common case where the target type also accepts 0 or positive integer literals, it should also implement [FromLiteralUnsigned].**
+///
+/// The first part (`VALID_LITERAL`) runs at compile-time, allowing you to perform input checks,
+/// where invalid input results in a compile error.
+///
 /// The second part (`into_self()`) runs at runtime, and is where you create your actual value,
 /// knowing that the input is guaranteed to be valid.
 ///
@@ -254,16 +906,29 @@ pub trait FromLiteralSigned<const LIT: i128> {
     ///
     /// If you want, you can use an unsafe 'unchecked' constructor, if one exists, since you have done any validation already.
     /// (But even if using a normal constructor, in all likelyhood the compiler is smart enough to remove the duplicate checks since the input is a literal value.)
+    ///
+    /// This method is `#[track_caller]`, so should it panic anyway, the panic points at the literal rather than at your implementation.
+    #[track_caller]
     fn into_self() -> Self;
 }
 
 macro_rules! unsigned_impl {
-    ($type:ty) => {
+    ($type:ty, $range:literal) => {
         impl<const LIT: u128> FromLiteralUnsigned<LIT> for $type {
             const VALID_LITERAL: u128 = {
-                let min = <$type>::MIN as u128;
+                // An unsigned literal is never below the minimum (which is at most 0),
+                // and casting a signed minimum to `u128` would wrap around.
                 let max = <$type>::MAX as u128;
-                assert!(LIT >= min && LIT <= max, "Out of range integer literal");
+                assert!(
+                    LIT <= max,
+                    concat!(
+                        "Out of range integer literal for ",
+                        stringify!($type),
+                        " (",
+                        $range,
+                        ")"
+                    )
+                );
                 LIT
             };
 
@@ -272,16 +937,36 @@ macro_rules! unsigned_impl {
                 <Self as FromLiteralUnsigned<LIT>>::VALID_LITERAL as $type
             }
         }
+
+        impl<const LIT: u128> ConstFromLiteralUnsigned<LIT> for $type {
+            const VALUE: Self = <Self as FromLiteralUnsigned<LIT>>::VALID_LITERAL as $type;
+        }
+
+        impl TryFromLiteralValue<u128> for $type {
+            #[inline]
+            fn try_from_value(value: u128) -> Result<Self, LiteralError> {
+                <$type>::try_from(value).map_err(|_| LiteralError::OutOfRange)
+            }
+        }
     };
 }
 
 macro_rules! signed_impl {
-    ($type:ty) => {
+    ($type:ty, $range:literal) => {
         impl<const LIT: i128> FromLiteralSigned<LIT> for $type {
             const VALID_LITERAL: i128 = {
                 let min = <$type>::MIN as i128;
                 let max = <$type>::MAX as i128;
-                assert!(LIT >= min && LIT <= max, "Out of range integer literal");
+                assert!(
+                    LIT >= min && LIT <= max,
+                    concat!(
+                        "Out of range integer literal for ",
+                        stringify!($type),
+                        " (",
+                        $range,
+                        ")"
+                    )
+                );
                 LIT
             };
 
@@ -290,52 +975,92 @@ macro_rules! signed_impl {
                 <Self as FromLiteralSigned<LIT>>::VALID_LITERAL as $type
             }
         }
+
+        impl<const LIT: i128> ConstFromLiteralSigned<LIT> for $type {
+            const VALUE: Self = <Self as FromLiteralSigned<LIT>>::VALID_LITERAL as $type;
+        }
+
+        impl TryFromLiteralValue<i128> for $type {
+            #[inline]
+            fn try_from_value(value: i128) -> Result<Self, LiteralError> {
+                <$type>::try_from(value).map_err(|_| LiteralError::OutOfRange)
+            }
+        }
     };
 }
 
-unsigned_impl!(u8);
-unsigned_impl!(u16);
-unsigned_impl!(u32);
-unsigned_impl!(u64);
-unsigned_impl!(u128);
-unsigned_impl!(usize);
+unsigned_impl!(u8, "0..=255");
+unsigned_impl!(u16, "0..=65535");
+unsigned_impl!(u32, "0..=4294967295");
+unsigned_impl!(u64, "0..=18446744073709551615");
+unsigned_impl!(u128, "0..=340282366920938463463374607431768211455");
+unsigned_impl!(usize, "0..=usize::MAX");
 
-unsigned_impl!(i8);
-unsigned_impl!(i16);
-unsigned_impl!(i32);
-unsigned_impl!(i64);
-unsigned_impl!(i128);
-unsigned_impl!(isize);
+unsigned_impl!(i8, "-128..=127");
+unsigned_impl!(i16, "-32768..=32767");
+unsigned_impl!(i32, "-2147483648..=2147483647");
+unsigned_impl!(i64, "-9223372036854775808..=9223372036854775807");
+unsigned_impl!(
+    i128,
+    "-170141183460469231731687303715884105728..=170141183460469231731687303715884105727"
+);
+unsigned_impl!(isize, "isize::MIN..=isize::MAX");
 
-signed_impl!(i8);
-signed_impl!(i16);
-signed_impl!(i32);
-signed_impl!(i64);
-signed_impl!(i128);
-signed_impl!(isize);
+signed_impl!(i8, "-128..=127");
+signed_impl!(i16, "-32768..=32767");
+signed_impl!(i32, "-2147483648..=2147483647");
+signed_impl!(i64, "-9223372036854775808..=9223372036854775807");
+signed_impl!(
+    i128,
+    "-170141183460469231731687303715884105728..=170141183460469231731687303715884105727"
+);
+signed_impl!(isize, "isize::MIN..=isize::MAX");
 
 macro_rules! nonzero_unsigned_impl {
-    ($type:ty, $orig_type:ty) => {
+    ($type:ty, $orig_type:ty, $range:literal) => {
         impl<const LIT: u128> FromLiteralUnsigned<LIT> for $type {
             const VALID_LITERAL: u128 = {
                 let max = <$orig_type>::MAX as u128;
                 assert!(LIT != 0, "NonZero integer literal was 0");
-                assert!(LIT <= max, "Out of range NonZero integer literal");
+                assert!(
+                    LIT <= max,
+                    concat!(
+                        "Out of range NonZero integer literal for ",
+                        stringify!($type),
+                        " (",
+                        $range,
+                        ")"
+                    )
+                );
                 LIT
             };
 
             #[inline]
             fn into_self() -> Self {
+                <Self as ConstFromLiteralUnsigned<LIT>>::VALUE
+            }
+        }
+
+        impl<const LIT: u128> ConstFromLiteralUnsigned<LIT> for $type {
+            const VALUE: Self = {
                 let raw = <Self as FromLiteralUnsigned<LIT>>::VALID_LITERAL as $orig_type;
                 // SAFETY: Bounds check happened at compile time
                 unsafe { <$type>::new_unchecked(raw) }
+            };
+        }
+
+        impl TryFromLiteralValue<u128> for $type {
+            #[inline]
+            fn try_from_value(value: u128) -> Result<Self, LiteralError> {
+                let raw = <$orig_type>::try_from(value).map_err(|_| LiteralError::OutOfRange)?;
+                <$type>::new(raw).ok_or(LiteralError::Zero)
             }
         }
     };
 }
 
 macro_rules! nonzero_signed_impl {
-    ($type:ty, $orig_type:ty) => {
+    ($type:ty, $orig_type:ty, $range:literal) => {
         impl<const LIT: i128> FromLiteralSigned<LIT> for $type {
             const VALID_LITERAL: i128 = {
                 let min = <$orig_type>::MIN as i128;
@@ -343,71 +1068,463 @@ macro_rules! nonzero_signed_impl {
                 assert!(LIT != 0, "NonZero integer literal was 0");
                 assert!(
                     LIT >= min && LIT <= max,
-                    "Out of range NonZero integer literal"
+                    concat!(
+                        "Out of range NonZero integer literal for ",
+                        stringify!($type),
+                        " (",
+                        $range,
+                        ")"
+                    )
                 );
                 LIT
             };
 
-            #[inline]
-            fn into_self() -> Self {
-                let raw = <Self as FromLiteralSigned<LIT>>::VALID_LITERAL as $orig_type;
-                // SAFETY: Bounds check happened at compile time
-                unsafe { <$type>::new_unchecked(raw) }
-            }
-        }
-    };
+            #[inline]
+            fn into_self() -> Self {
+                <Self as ConstFromLiteralSigned<LIT>>::VALUE
+            }
+        }
+
+        impl<const LIT: i128> ConstFromLiteralSigned<LIT> for $type {
+            const VALUE: Self = {
+                let raw = <Self as FromLiteralSigned<LIT>>::VALID_LITERAL as $orig_type;
+                // SAFETY: Bounds check happened at compile time
+                unsafe { <$type>::new_unchecked(raw) }
+            };
+        }
+
+        impl TryFromLiteralValue<i128> for $type {
+            #[inline]
+            fn try_from_value(value: i128) -> Result<Self, LiteralError> {
+                let raw = <$orig_type>::try_from(value).map_err(|_| LiteralError::OutOfRange)?;
+                <$type>::new(raw).ok_or(LiteralError::Zero)
+            }
+        }
+    };
+}
+
+nonzero_unsigned_impl!(NonZeroU8, u8, "1..=255");
+nonzero_unsigned_impl!(NonZeroU16, u16, "1..=65535");
+nonzero_unsigned_impl!(NonZeroU32, u32, "1..=4294967295");
+nonzero_unsigned_impl!(NonZeroU64, u64, "1..=18446744073709551615");
+nonzero_unsigned_impl!(
+    NonZeroU128,
+    u128,
+    "1..=340282366920938463463374607431768211455"
+);
+nonzero_unsigned_impl!(NonZeroUsize, usize, "1..=usize::MAX");
+
+nonzero_unsigned_impl!(NonZeroI8, i8, "-128..=127");
+nonzero_unsigned_impl!(NonZeroI16, i16, "-32768..=32767");
+nonzero_unsigned_impl!(NonZeroI32, i32, "-2147483648..=2147483647");
+nonzero_unsigned_impl!(
+    NonZeroI64,
+    i64,
+    "-9223372036854775808..=9223372036854775807"
+);
+nonzero_unsigned_impl!(
+    NonZeroI128,
+    i128,
+    "-170141183460469231731687303715884105728..=170141183460469231731687303715884105727"
+);
+nonzero_unsigned_impl!(NonZeroIsize, isize, "isize::MIN..=isize::MAX");
+nonzero_signed_impl!(NonZeroI8, i8, "-128..=127");
+nonzero_signed_impl!(NonZeroI16, i16, "-32768..=32767");
+nonzero_signed_impl!(NonZeroI32, i32, "-2147483648..=2147483647");
+nonzero_signed_impl!(
+    NonZeroI64,
+    i64,
+    "-9223372036854775808..=9223372036854775807"
+);
+nonzero_signed_impl!(
+    NonZeroI128,
+    i128,
+    "-170141183460469231731687303715884105728..=170141183460469231731687303715884105727"
+);
+nonzero_signed_impl!(NonZeroIsize, isize, "isize::MIN..=isize::MAX");
+
+/// Implementation for [Wrapping], which leaves the check of the literal to the wrapped type `T`.
+///
+/// So a literal has to fit in `T`: wrapping only applies to arithmetic, not to the literal itself.
+///
+/// ```rust
+/// # use overloaded_literals::overloaded_literals;
+/// # use core::num::Wrapping;
+///
+/// #[overloaded_literals]
+/// pub fn correct() {
+///    let counter: Wrapping<u16> = 65535;
+///    let one: Wrapping<u16> = 1;
+///    assert_eq!((counter + one).0, 0);
+/// }
+/// correct()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use core::num::Wrapping;
+///
+/// #[overloaded_literals]
+/// pub fn oops_out_of_range() {
+///    let counter: Wrapping<u16> = 65536;
+/// }
+/// oops_out_of_range()
+/// ```
+impl<T: FromLiteralUnsigned<LIT>, const LIT: u128> FromLiteralUnsigned<LIT> for Wrapping<T> {
+    const VALID_LITERAL: u128 = T::VALID_LITERAL;
+
+    #[inline]
+    fn into_self() -> Self {
+        Wrapping(T::into_self())
+    }
+}
+
+impl<T: ConstFromLiteralUnsigned<LIT>, const LIT: u128> ConstFromLiteralUnsigned<LIT>
+    for Wrapping<T>
+{
+    const VALUE: Self = Wrapping(T::VALUE);
+}
+
+impl<T: TryFromLiteralValue<Value>, Value> TryFromLiteralValue<Value> for Wrapping<T> {
+    #[inline]
+    fn try_from_value(value: Value) -> Result<Self, LiteralError> {
+        T::try_from_value(value).map(Wrapping)
+    }
+}
+
+/// Implementation for [Wrapping], which leaves the check of the literal to the wrapped type `T`.
+impl<T: FromLiteralSigned<LIT>, const LIT: i128> FromLiteralSigned<LIT> for Wrapping<T> {
+    const VALID_LITERAL: i128 = T::VALID_LITERAL;
+
+    #[inline]
+    fn into_self() -> Self {
+        Wrapping(T::into_self())
+    }
+}
+
+impl<T: ConstFromLiteralSigned<LIT>, const LIT: i128> ConstFromLiteralSigned<LIT> for Wrapping<T> {
+    const VALUE: Self = Wrapping(T::VALUE);
+}
+
+/// Implementation for [Saturating](core::num::Saturating), which leaves the check of the literal to the wrapped type `T`,
+/// just like for [Wrapping].
+///
+/// Requires the `saturating` feature, as `Saturating` is only available since Rust 1.74.
+///
+/// ```rust
+/// # use overloaded_literals::overloaded_literals;
+/// # use core::num::Saturating;
+///
+/// #[overloaded_literals]
+/// pub fn correct() {
+///    let level: Saturating<i8> = -5;
+///    assert_eq!((level - Saturating(i8::MAX)).0, i8::MIN);
+/// }
+/// correct()
+/// ```
+#[cfg(feature = "saturating")]
+impl<T: FromLiteralUnsigned<LIT>, const LIT: u128> FromLiteralUnsigned<LIT>
+    for core::num::Saturating<T>
+{
+    const VALID_LITERAL: u128 = T::VALID_LITERAL;
+
+    #[inline]
+    fn into_self() -> Self {
+        core::num::Saturating(T::into_self())
+    }
+}
+
+#[cfg(feature = "saturating")]
+impl<T: ConstFromLiteralUnsigned<LIT>, const LIT: u128> ConstFromLiteralUnsigned<LIT>
+    for core::num::Saturating<T>
+{
+    const VALUE: Self = core::num::Saturating(T::VALUE);
+}
+
+#[cfg(feature = "saturating")]
+impl<T: TryFromLiteralValue<Value>, Value> TryFromLiteralValue<Value> for core::num::Saturating<T> {
+    #[inline]
+    fn try_from_value(value: Value) -> Result<Self, LiteralError> {
+        T::try_from_value(value).map(core::num::Saturating)
+    }
+}
+
+/// Implementation for [Saturating](core::num::Saturating), which leaves the check of the literal to the wrapped type `T`.
+#[cfg(feature = "saturating")]
+impl<T: FromLiteralSigned<LIT>, const LIT: i128> FromLiteralSigned<LIT>
+    for core::num::Saturating<T>
+{
+    const VALID_LITERAL: i128 = T::VALID_LITERAL;
+
+    #[inline]
+    fn into_self() -> Self {
+        core::num::Saturating(T::into_self())
+    }
+}
+
+#[cfg(feature = "saturating")]
+impl<T: ConstFromLiteralSigned<LIT>, const LIT: i128> ConstFromLiteralSigned<LIT>
+    for core::num::Saturating<T>
+{
+    const VALUE: Self = core::num::Saturating(T::VALUE);
+}
+
+/// Implementation to create an [Ordering] from the integer literals `-1`, `0` and `1`,
+/// meaning [Less](Ordering::Less), [Equal](Ordering::Equal) and [Greater](Ordering::Greater) respectively.
+///
+/// (This matches the convention used by e.g. C's `strcmp` and Java's `compareTo`.)
+///
+/// ```rust
+/// # use overloaded_literals::overloaded_literals;
+/// # use core::cmp::Ordering;
+///
+/// #[overloaded_literals]
+/// pub fn correct() {
+///    let less: Ordering = -1;
+///    let equal: Ordering = 0;
+///    let greater: Ordering = 1;
+///    assert_eq!((less, equal, greater), (Ordering::Less, Ordering::Equal, Ordering::Greater));
+/// }
+/// correct()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use core::cmp::Ordering;
+///
+/// #[overloaded_literals]
+/// pub fn oops_out_of_range() {
+///    let x: Ordering = 2;
+/// }
+/// oops_out_of_range()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use core::cmp::Ordering;
+///
+/// #[overloaded_literals]
+/// pub fn oops_out_of_range() {
+///    let x: Ordering = -2;
+/// }
+/// oops_out_of_range()
+/// ```
+impl<const LIT: u128> FromLiteralUnsigned<LIT> for Ordering {
+    const VALID_LITERAL: u128 = {
+        assert!(LIT <= 1, "Ordering literal has to be -1, 0 or 1");
+        LIT
+    };
+
+    #[inline]
+    fn into_self() -> Self {
+        <Self as ConstFromLiteralUnsigned<LIT>>::VALUE
+    }
+}
+
+impl<const LIT: u128> ConstFromLiteralUnsigned<LIT> for Ordering {
+    const VALUE: Self = match <Self as FromLiteralUnsigned<LIT>>::VALID_LITERAL {
+        0 => Ordering::Equal,
+        _ => Ordering::Greater,
+    };
+}
+
+impl TryFromLiteralValue<u128> for Ordering {
+    #[inline]
+    fn try_from_value(value: u128) -> Result<Self, LiteralError> {
+        match value {
+            0 => Ok(Ordering::Equal),
+            1 => Ok(Ordering::Greater),
+            _ => Err(LiteralError::OutOfRange),
+        }
+    }
+}
+
+impl<const LIT: i128> FromLiteralSigned<LIT> for Ordering {
+    const VALID_LITERAL: i128 = {
+        assert!(
+            LIT >= -1 && LIT <= 1,
+            "Ordering literal has to be -1, 0 or 1"
+        );
+        LIT
+    };
+
+    #[inline]
+    fn into_self() -> Self {
+        <Self as ConstFromLiteralSigned<LIT>>::VALUE
+    }
+}
+
+impl<const LIT: i128> ConstFromLiteralSigned<LIT> for Ordering {
+    const VALUE: Self = match <Self as FromLiteralSigned<LIT>>::VALID_LITERAL {
+        -1 => Ordering::Less,
+        0 => Ordering::Equal,
+        _ => Ordering::Greater,
+    };
+}
+
+impl TryFromLiteralValue<i128> for Ordering {
+    #[inline]
+    fn try_from_value(value: i128) -> Result<Self, LiteralError> {
+        match value {
+            -1 => Ok(Ordering::Less),
+            0 => Ok(Ordering::Equal),
+            1 => Ok(Ordering::Greater),
+            _ => Err(LiteralError::OutOfRange),
+        }
+    }
+}
+
+/// Build your datatype from a boolean literal (`false` or `true`).
+///
+/// The [macro@overloaded_literals] macro turns boolean literals like
+/// ```compile_only
+/// true
+/// ```
+/// into calls to
+///
+/// ```compile_only
+/// FromLiteralBool::<true>::VALID_LITERAL::into_self()
+/// ```
+///
+///
+/// The first part (`VALID_LITERAL`) runs at compile-time, allowing you to perform input checks,
+/// where invalid input results in a compile error.
+///
+/// The second part (`into_self()`) runs at runtime, and is where you create your actual value,
+/// knowing that the input is guaranteed to be valid.
+///
+/// ```txt
+/// FromLiteralBool::<true>::VALID_LITERAL.into_self()
+/// ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+///               compile time             ^^^^^^^^^^^
+///                                          runtime
+/// ```
+///
+pub trait FromLiteralBool<const LIT: bool> {
+    /// The definition of `VALID_LITERAL` is evaluated at compile-time.
+    ///
+    /// Inside this definition you have access to `LIT`.
+    ///
+    /// An implementation of `VALID_LITERAL` should perform input checking:
+    /// - If the input is valid, return `LIT` unchanged.
+    /// - If the input is invalid, [panic](core::panic!).
+    ///   Because this is evaluated at compile-time, this results in a compile error.
+    const VALID_LITERAL: bool;
+
+    /// Turns a [VALID_LITERAL](FromLiteralBool::VALID_LITERAL) into the actual runtime value.
+    ///
+    /// This part runs at runtime.
+    ///
+    /// You have access to [VALID_LITERAL](FromLiteralBool::VALID_LITERAL) (using the syntax `let val = <Self as FromLiteralBool<LIT>>::VALID_LITERAL;`),
+    /// and should turn it into your desired value.
+    ///
+    /// If you want, you can use an unsafe 'unchecked' constructor, if one exists, since you have done any validation already.
+    /// (But even if using a normal constructor, in all likelyhood the compiler is smart enough to remove the duplicate checks since the input is a literal value.)
+    ///
+    /// This method is `#[track_caller]`, so should it panic anyway, the panic points at the literal rather than at your implementation.
+    #[track_caller]
+    fn into_self() -> Self;
+}
+
+impl<const LIT: bool> FromLiteralBool<LIT> for bool {
+    const VALID_LITERAL: bool = LIT;
+
+    #[inline]
+    fn into_self() -> Self {
+        <Self as FromLiteralBool<LIT>>::VALID_LITERAL
+    }
+}
+
+impl TryFromLiteralValue<bool> for bool {
+    #[inline]
+    fn try_from_value(value: bool) -> Result<Self, LiteralError> {
+        Ok(value)
+    }
+}
+
+/// Build your datatype from a `char` literal like `'a'` or `'λ'`.
+///
+/// The [macro@overloaded_literals] macro turns character literals like
+/// ```compile_only
+/// 'a'
+/// ```
+/// into calls to
+///
+/// ```compile_only
+/// FromLiteralChar::<'a'>::VALID_LITERAL::into_self()
+/// ```
+///
+///
+/// The first part (`VALID_LITERAL`) runs at compile-time, allowing you to perform input checks,
+/// where invalid input results in a compile error.
+///
+/// The second part (`into_self()`) runs at runtime, and is where you create your actual value,
+/// knowing that the input is guaranteed to be valid.
+///
+/// ```txt
+/// FromLiteralChar::<'a'>::VALID_LITERAL.into_self()
+/// ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+///               compile time             ^^^^^^^^^^^
+///                                          runtime
+/// ```
+///
+pub trait FromLiteralChar<const LIT: char> {
+    /// The definition of `VALID_LITERAL` is evaluated at compile-time.
+    ///
+    /// Inside this definition you have access to `LIT`.
+    ///
+    /// An implementation of `VALID_LITERAL` should perform input checking:
+    /// - If the input is valid, return `LIT` unchanged.
+    /// - If the input is invalid, [panic](core::panic!).
+    ///   Because this is evaluated at compile-time, this results in a compile error.
+    const VALID_LITERAL: char;
+
+    /// Turns a [VALID_LITERAL](FromLiteralChar::VALID_LITERAL) into the actual runtime value.
+    ///
+    /// This part runs at runtime.
+    ///
+    /// You have access to [VALID_LITERAL](FromLiteralChar::VALID_LITERAL) (using the syntax `let val = <Self as FromLiteralChar<LIT>>::VALID_LITERAL;`),
+    /// and should turn it into your desired value.
+    ///
+    /// If you want, you can use an unsafe 'unchecked' constructor, if one exists, since you have done any validation already.
+    /// (But even if using a normal constructor, in all likelyhood the compiler is smart enough to remove the duplicate checks since the input is a literal value.)
+    ///
+    /// This method is `#[track_caller]`, so should it panic anyway, the panic points at the literal rather than at your implementation.
+    #[track_caller]
+    fn into_self() -> Self;
 }
 
-nonzero_unsigned_impl!(NonZeroU8, u8);
-nonzero_unsigned_impl!(NonZeroU16, u16);
-nonzero_unsigned_impl!(NonZeroU32, u32);
-nonzero_unsigned_impl!(NonZeroU64, u64);
-nonzero_unsigned_impl!(NonZeroU128, u128);
-nonzero_unsigned_impl!(NonZeroUsize, usize);
-
-nonzero_unsigned_impl!(NonZeroI8, i8);
-nonzero_unsigned_impl!(NonZeroI16, i16);
-nonzero_unsigned_impl!(NonZeroI32, i32);
-nonzero_unsigned_impl!(NonZeroI64, i64);
-nonzero_unsigned_impl!(NonZeroI128, i128);
-nonzero_unsigned_impl!(NonZeroIsize, isize);
-nonzero_signed_impl!(NonZeroI8, i8);
-nonzero_signed_impl!(NonZeroI16, i16);
-nonzero_signed_impl!(NonZeroI32, i32);
-nonzero_signed_impl!(NonZeroI64, i64);
-nonzero_signed_impl!(NonZeroI128, i128);
-nonzero_signed_impl!(NonZeroIsize, isize);
-
-impl<T: FromLiteralUnsigned<LIT>, const LIT: u128> FromLiteralUnsigned<LIT> for Wrapping<T> {
-    const VALID_LITERAL: u128 = T::VALID_LITERAL;
+impl<const LIT: char> FromLiteralChar<LIT> for char {
+    const VALID_LITERAL: char = LIT;
 
     #[inline]
     fn into_self() -> Self {
-        Wrapping(T::into_self())
+        <Self as FromLiteralChar<LIT>>::VALID_LITERAL
     }
 }
 
-impl<T: FromLiteralSigned<LIT>, const LIT: i128> FromLiteralSigned<LIT> for Wrapping<T> {
-    const VALID_LITERAL: i128 = T::VALID_LITERAL;
-
+impl TryFromLiteralValue<char> for char {
     #[inline]
-    fn into_self() -> Self {
-        Wrapping(T::into_self())
+    fn try_from_value(value: char) -> Result<Self, LiteralError> {
+        Ok(value)
     }
 }
 
-/// Build your datatype from a boolean literal (`false` or `true`).
+/// Build your datatype from a byte literal like `b'A'` or `b'\n'`.
 ///
-/// The [macro@overloaded_literals] macro turns boolean literals like
+/// The [macro@overloaded_literals] macro turns byte literals like
 /// ```compile_only
-/// true
+/// b'A'
 /// ```
 /// into calls to
 ///
 /// ```compile_only
-/// FromLiteralBool::<true>::VALID_LITERAL::into_self()
+/// FromLiteralByte::<b'A'>::VALID_LITERAL::into_self()
 /// ```
 ///
+/// This is separate from [FromLiteralUnsigned], even though a byte literal is just a `u8`:
+/// writing `b'A'` rather than `65` says that the value is meant as a byte (usually, an ASCII character),
+/// so types can accept one but not the other.
 ///
 /// The first part (`VALID_LITERAL`) runs at compile-time, allowing you to perform input checks,
 /// where invalid input results in a compile error.
@@ -416,13 +1533,14 @@ impl<T: FromLiteralSigned<LIT>, const LIT: i128> FromLiteralSigned<LIT> for Wrap
 /// knowing that the input is guaranteed to be valid.
 ///
 /// ```txt
-/// FromLiteralBool::<true>::VALID_LITERAL.into_self()
-/// ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
-///               compile time             ^^^^^^^^^^^
-///                                          runtime
+/// FromLiteralByte::<b'A'>::VALID_LITERAL.into_self()
+/// ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+///               compile time              ^^^^^^^^^^^
+///                                           runtime
 /// ```
 ///
-pub trait FromLiteralBool<const LIT: bool> {
+/// See [PrintableByte](examples::PrintableByte) for an example whose `into_self()` uses an unchecked constructor.
+pub trait FromLiteralByte<const LIT: u8> {
     /// The definition of `VALID_LITERAL` is evaluated at compile-time.
     ///
     /// Inside this definition you have access to `LIT`.
@@ -431,26 +1549,36 @@ pub trait FromLiteralBool<const LIT: bool> {
     /// - If the input is valid, return `LIT` unchanged.
     /// - If the input is invalid, [panic](core::panic!).
     ///   Because this is evaluated at compile-time, this results in a compile error.
-    const VALID_LITERAL: bool;
+    const VALID_LITERAL: u8;
 
-    /// Turns a [VALID_LITERAL](FromLiteralBool::VALID_LITERAL) into the actual runtime value.
+    /// Turns a [VALID_LITERAL](FromLiteralByte::VALID_LITERAL) into the actual runtime value.
     ///
     /// This part runs at runtime.
     ///
-    /// You have access to [VALID_LITERAL](FromLiteralBool::VALID_LITERAL) (using the syntax `let val = <Self as FromLiteralBool<LIT>>::VALID_LITERAL;`),
+    /// You have access to [VALID_LITERAL](FromLiteralByte::VALID_LITERAL) (using the syntax `let val = <Self as FromLiteralByte<LIT>>::VALID_LITERAL;`),
     /// and should turn it into your desired value.
     ///
     /// If you want, you can use an unsafe 'unchecked' constructor, if one exists, since you have done any validation already.
     /// (But even if using a normal constructor, in all likelyhood the compiler is smart enough to remove the duplicate checks since the input is a literal value.)
+    ///
+    /// This method is `#[track_caller]`, so should it panic anyway, the panic points at the literal rather than at your implementation.
+    #[track_caller]
     fn into_self() -> Self;
 }
 
-impl<const LIT: bool> FromLiteralBool<LIT> for bool {
-    const VALID_LITERAL: bool = LIT;
+impl<const LIT: u8> FromLiteralByte<LIT> for u8 {
+    const VALID_LITERAL: u8 = LIT;
 
     #[inline]
     fn into_self() -> Self {
-        <Self as FromLiteralBool<LIT>>::VALID_LITERAL
+        <Self as FromLiteralByte<LIT>>::VALID_LITERAL
+    }
+}
+
+impl TryFromLiteralValue<u8> for u8 {
+    #[inline]
+    fn try_from_value(value: u8) -> Result<Self, LiteralError> {
+        Ok(value)
     }
 }
 
@@ -474,6 +1602,9 @@ pub trait FromLiteralFloat<TFloat: TypeFloat> {
     ///
     /// If you want, you can use an unsafe 'unchecked' constructor, if one exists, since you have done any validation already.
     /// (But even if using a normal constructor, in all likelyhood the compiler is smart enough to remove the duplicate checks since the input is a literal value.)
+    ///
+    /// This method is `#[track_caller]`, so should it panic anyway, the panic points at the literal rather than at your implementation.
+    #[track_caller]
     fn into_self() -> Self;
 }
 
@@ -486,6 +1617,13 @@ impl<TFloat: TypeFloat> FromLiteralFloat<TFloat> for f64 {
     }
 }
 
+impl TryFromLiteralValue<f64> for f64 {
+    #[inline]
+    fn try_from_value(value: f64) -> Result<Self, LiteralError> {
+        Ok(value)
+    }
+}
+
 impl<TFloat: TypeFloat> FromLiteralFloat<TFloat> for f32 {
     const VALID_LITERAL: f64 = TFloat::FLOAT;
 
@@ -495,6 +1633,13 @@ impl<TFloat: TypeFloat> FromLiteralFloat<TFloat> for f32 {
     }
 }
 
+impl TryFromLiteralValue<f64> for f32 {
+    #[inline]
+    fn try_from_value(value: f64) -> Result<Self, LiteralError> {
+        Ok(value as f32)
+    }
+}
+
 /// Implementation to create a  `&'static CStr` from a string literal.
 /// Requires the given string literal to be:
 /// - nul terminated
@@ -558,6 +1703,169 @@ impl<TStr: TypeStr> FromLiteralStr<TStr> for &'static CStr {
     }
 }
 
+impl<'a> TryFromLiteralValue<&'a str> for &'a CStr {
+    #[inline]
+    fn try_from_value(value: &'a str) -> Result<Self, LiteralError> {
+        CStr::from_bytes_with_nul(value.as_bytes()).map_err(|_| LiteralError::InvalidStr)
+    }
+}
+
+/// Implementation to create an owned `CString` from a string literal.
+/// Requires the given string literal to not contain any nul characters.
+/// (Unlike for `&'static CStr`, the nul terminator is added by `CString` itself.)
+///
+/// This turns the error `CString::new` would return at runtime into a compile error.
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use std::ffi::CString;
+///
+/// #[overloaded_literals]
+/// pub fn oops_nul_in_the_middle() {
+///    let x: CString = "Ba\0nana";
+///    println!("{:?}", x);
+/// }
+/// oops_nul_in_the_middle()
+/// ```
+///
+/// ```rust
+/// # use overloaded_literals::overloaded_literals;
+/// # use std::ffi::CString;
+///
+/// #[overloaded_literals]
+/// pub fn correct() {
+///    let x: CString = "Dragon fruit";
+///    assert_eq!(x.as_bytes_with_nul(), b"Dragon fruit\0");
+/// }
+/// correct()
+/// ```
+#[cfg(feature = "alloc")]
+impl<TStr: TypeStr> FromLiteralStr<TStr> for alloc::ffi::CString {
+    const VALID_LITERAL: &'static str = {
+        let bytes = TStr::STR.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            assert!(bytes[i] != 0, "CString cannot contain nul bytes");
+            i += 1;
+        }
+        TStr::STR
+    };
+
+    #[inline]
+    fn into_self() -> Self {
+        let string = <Self as FromLiteralStr<TStr>>::VALID_LITERAL;
+        match alloc::ffi::CString::new(string) {
+            Ok(c_string) => c_string,
+            Err(_) => unreachable!("VALID_LITERAL is checked at compile time"),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> TryFromLiteralValue<&'a str> for alloc::ffi::CString {
+    #[inline]
+    fn try_from_value(value: &'a str) -> Result<Self, LiteralError> {
+        alloc::ffi::CString::new(value).map_err(|_| LiteralError::InvalidStr)
+    }
+}
+
+/// Implementation to create a `std::net::Ipv4Addr` from a dotted-quad string literal like `"192.168.0.1"`.
+/// Requires the given string literal to:
+/// - consist of exactly four parts, separated by `.`
+/// - have a decimal number for each part, from `0` up to and including `255`
+/// - not have leading zeros in any part (like `"010"`), as these are often meant as octal
+///
+/// ```rust
+/// # use overloaded_literals::overloaded_literals;
+/// # use std::net::Ipv4Addr;
+///
+/// #[overloaded_literals]
+/// pub fn correct() {
+///    let ip: Ipv4Addr = "192.168.0.1";
+///    assert_eq!(ip.octets(), [192, 168, 0, 1]);
+/// }
+/// correct()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use std::net::Ipv4Addr;
+///
+/// #[overloaded_literals]
+/// pub fn oops_out_of_range() {
+///    let ip: Ipv4Addr = "192.168.0.256";
+/// }
+/// oops_out_of_range()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use std::net::Ipv4Addr;
+///
+/// #[overloaded_literals]
+/// pub fn oops_too_few_parts() {
+///    let ip: Ipv4Addr = "10.0.1";
+/// }
+/// oops_too_few_parts()
+/// ```
+#[cfg(feature = "std")]
+impl<TStr: TypeStr> FromLiteralStr<TStr> for std::net::Ipv4Addr {
+    const VALID_LITERAL: &'static str = {
+        parse_ipv4(TStr::STR);
+        TStr::STR
+    };
+
+    #[inline]
+    fn into_self() -> Self {
+        let [a, b, c, d] = parse_ipv4(<Self as FromLiteralStr<TStr>>::VALID_LITERAL);
+        std::net::Ipv4Addr::new(a, b, c, d)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> TryFromLiteralValue<&'a str> for std::net::Ipv4Addr {
+    #[inline]
+    fn try_from_value(value: &'a str) -> Result<Self, LiteralError> {
+        value.parse().map_err(|_| LiteralError::InvalidStr)
+    }
+}
+
+#[cfg(feature = "std")]
+const fn parse_ipv4(literal: &str) -> [u8; 4] {
+    let bytes = literal.as_bytes();
+    let mut octets = [0; 4];
+    let mut part = 0;
+    let mut index = 0;
+    loop {
+        assert!(part < 4, "Ipv4Addr literal has more than four parts");
+        let start = index;
+        let mut value: u32 = 0;
+        while index < bytes.len() && bytes[index] != b'.' {
+            let byte = bytes[index];
+            assert!(
+                byte.is_ascii_digit(),
+                "Ipv4Addr literal can only contain digits and `.`"
+            );
+            value = value * 10 + (byte - b'0') as u32;
+            assert!(value <= 255, "Ipv4Addr literal part is larger than 255");
+            index += 1;
+        }
+        assert!(index > start, "Ipv4Addr literal has an empty part");
+        assert!(
+            index - start == 1 || bytes[start] != b'0',
+            "Ipv4Addr literal part has a leading zero"
+        );
+        octets[part] = value as u8;
+        part += 1;
+        if index == bytes.len() {
+            break;
+        }
+        index += 1; // Skip the `.`
+    }
+    assert!(part == 4, "Ipv4Addr literal has fewer than four parts");
+    octets
+}
+
 // pub trait FromLiteralFloat<const LIT: f64> {
 //     /// The definition of `VALID_LITERAL` is evaluated at compile-time.
 //     ///
@@ -607,23 +1915,6 @@ impl<TStr: TypeStr> FromLiteralStr<TStr> for &'static CStr {
 //     }
 // }
 
-// const fn const_str_eq(lhs: &str, rhs: &str) -> bool {
-//     if lhs.len() != rhs.len() {
-//         return false;
-//     }
-//     let len = lhs.len();
-//     let lhs_bytes = lhs.as_bytes();
-//     let rhs_bytes = rhs.as_bytes();
-//     let mut index = 0;
-//     while index < len {
-//         if lhs_bytes[index] != rhs_bytes[index] {
-//             return false;
-//         }
-//         index += 1;
-//     }
-//     true
-// }
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -638,6 +1929,125 @@ mod tests {
         // let x: i8 = FromLiteralSigned::<-200>::into_self();
     }
 
+    #[test]
+    fn unsigned_literals_for_signed_types() {
+        let x: i8 = FromLiteralUnsigned::<0>::into_self();
+        assert_eq!(x, 0);
+        let x: i8 = FromLiteralUnsigned::<127>::into_self();
+        assert_eq!(x, i8::MAX);
+        let x: i64 = FromLiteralUnsigned::<9223372036854775807>::into_self();
+        assert_eq!(x, i64::MAX);
+        let x: Wrapping<i16> = FromLiteralUnsigned::<1>::into_self();
+        assert_eq!(x, Wrapping(1));
+    }
+
+    #[test]
+    fn wrapping_usize_bounds() {
+        #[cfg(target_pointer_width = "16")]
+        let max: Wrapping<usize> = FromLiteralUnsigned::<65535>::into_self();
+        #[cfg(target_pointer_width = "32")]
+        let max: Wrapping<usize> = FromLiteralUnsigned::<4294967295>::into_self();
+        #[cfg(target_pointer_width = "64")]
+        let max: Wrapping<usize> = FromLiteralUnsigned::<18446744073709551615>::into_self();
+        assert_eq!(max.0, usize::MAX);
+
+        let one: Wrapping<usize> = FromLiteralUnsigned::<1>::into_self();
+        assert_eq!((max + one).0, 0);
+
+        let min: Wrapping<isize> = FromLiteralSigned::<{ isize::MIN as i128 }>::into_self();
+        assert_eq!(min.0, isize::MIN);
+        assert_eq!((min - Wrapping(1)).0, isize::MAX);
+    }
+
+    #[test]
+    fn nonzero_usize_bounds() {
+        #[cfg(target_pointer_width = "16")]
+        let max: NonZeroUsize = FromLiteralUnsigned::<65535>::into_self();
+        #[cfg(target_pointer_width = "32")]
+        let max: NonZeroUsize = FromLiteralUnsigned::<4294967295>::into_self();
+        #[cfg(target_pointer_width = "64")]
+        let max: NonZeroUsize = FromLiteralUnsigned::<18446744073709551615>::into_self();
+        assert_eq!(max.get(), usize::MAX);
+
+        let max: NonZeroUsize = FromLiteralUnsigned::<{ usize::MAX as u128 }>::into_self();
+        assert_eq!(max.get(), usize::MAX);
+
+        let min: NonZeroUsize = FromLiteralUnsigned::<1>::into_self();
+        assert_eq!(min.get(), 1);
+    }
+
+    // These check the NonZero implementations at their boundaries.
+    // Their `unsafe` `new_unchecked` calls only run while evaluating the const `VALUE`,
+    // and const evaluation itself rejects a zero `NonZero`, so a wrong bounds check fails to compile here.
+    macro_rules! nonzero_unsigned_bounds {
+        ($($type:ty, $orig_type:ty);* $(;)?) => {$(
+            let one: $type = FromLiteralUnsigned::<1>::into_self();
+            assert_eq!(one.get(), 1);
+            let max: $type = FromLiteralUnsigned::<{ <$orig_type>::MAX as u128 }>::into_self();
+            assert_eq!(max.get(), <$orig_type>::MAX);
+        )*};
+    }
+
+    macro_rules! nonzero_signed_bounds {
+        ($($type:ty, $orig_type:ty);* $(;)?) => {$(
+            let min: $type = FromLiteralSigned::<{ <$orig_type>::MIN as i128 }>::into_self();
+            assert_eq!(min.get(), <$orig_type>::MIN);
+            let minus_one: $type = FromLiteralSigned::<-1>::into_self();
+            assert_eq!(minus_one.get(), -1);
+            let one: $type = FromLiteralSigned::<1>::into_self();
+            assert_eq!(one.get(), 1);
+            let max: $type = FromLiteralSigned::<{ <$orig_type>::MAX as i128 }>::into_self();
+            assert_eq!(max.get(), <$orig_type>::MAX);
+        )*};
+    }
+
+    #[test]
+    fn nonzero_unsigned_literal_bounds() {
+        nonzero_unsigned_bounds!(
+            NonZeroU8, u8;
+            NonZeroU16, u16;
+            NonZeroU32, u32;
+            NonZeroU64, u64;
+            NonZeroU128, u128;
+            NonZeroUsize, usize;
+            NonZeroI8, i8;
+            NonZeroI16, i16;
+            NonZeroI32, i32;
+            NonZeroI64, i64;
+            NonZeroI128, i128;
+            NonZeroIsize, isize;
+        );
+    }
+
+    // These exercise the `unsafe` calls which do run at runtime, whose safety relies on the compile-time checks.
+    // Run them under Miri (`cargo +nightly miri test --lib`) to check that no UB occurs.
+    #[test]
+    fn unchecked_constructors_at_runtime() {
+        use examples::{PrintableByte, SizeAlign};
+
+        let space: PrintableByte = FromLiteralByte::<b' '>::into_self();
+        let tilde: PrintableByte = FromLiteralByte::<b'~'>::into_self();
+        assert_eq!((space.get(), tilde.get()), (b' ', b'~'));
+
+        let page: SizeAlign<4096> = FromLiteralUnsigned::<8192>::into_self();
+        let layout = page.layout();
+        assert_eq!((layout.size(), layout.align()), (8192, 4096));
+        let empty: SizeAlign<1> = FromLiteralUnsigned::<0>::into_self();
+        assert_eq!(empty.layout().size(), 0);
+    }
+
+    #[test]
+    fn nonzero_signed_literal_bounds() {
+        nonzero_signed_bounds!(
+            NonZeroI8, i8;
+            NonZeroI16, i16;
+            NonZeroI32, i32;
+            NonZeroI64, i64;
+            NonZeroI128, i128;
+            NonZeroIsize, isize;
+        );
+    }
+
     // #[test]
     // fn compile_time_error_on_invalid_inputs() {
     //     let y: u8 = FromLiteralSigned::<1024>::into_self();
@@ -661,6 +2071,246 @@ mod tests {
         println!("greeting: {y:?}");
     }
 
+    #[test]
+    fn type_str_concat() {
+        use tlist::TList;
+        use type_str::{Byte, Concat};
+
+        type Foo = TList![Byte<b'f'>, Byte<b'o'>, Byte<b'o'>];
+        type Bar = TList![Byte<b'b'>, Byte<b'a'>, Byte<b'r'>];
+        type Lambda = TList![Byte<0xCE>, Byte<0xBB>];
+
+        assert_eq!(<Concat<Foo, Bar> as TypeStr>::STR, "foobar");
+        assert_eq!(<Concat<Bar, Foo> as TypeStr>::STR, "barfoo");
+        assert_eq!(<Concat<Foo, Lambda> as TypeStr>::STR, "fooλ");
+        assert_eq!(<Concat<Foo, Bar> as TList>::LEN, 6);
+        assert_eq!(<Concat<Foo, tlist::TNil> as TypeStr>::STR, "foo");
+        assert_eq!(<Concat<tlist::TNil, Bar> as TypeByteStr>::BYTES, b"bar");
+    }
+
+    #[test]
+    fn type_str_utf8() {
+        use type_str::{is_valid_utf8, next_char};
+
+        let cases: [&[u8]; 14] = [
+            b"",
+            b"hello",
+            "λόγος 🦀".as_bytes(),
+            b"\x7F\xC2\x80\xDF\xBF",
+            b"\xEF\xBF\xBF\xF4\x8F\xBF\xBF",
+            b"\x80",
+            b"\xC0\x80",
+            b"\xC3",
+            b"\xE0\x9F\xBF",
+            b"\xED\xBF\xBF",
+            b"\xF0\x8F\xBF\xBF",
+            b"\xF4\x90\x80\x80",
+            b"\xF5\x80\x80\x80",
+            b"\xE2\x82a",
+        ];
+        for bytes in cases {
+            assert_eq!(
+                is_valid_utf8(bytes),
+                core::str::from_utf8(bytes).is_ok(),
+                "{bytes:?}"
+            );
+        }
+
+        let string = "aλ€🦀";
+        let mut decoded = std::vec::Vec::new();
+        let mut index = 0;
+        while let Some((char, next)) = next_char(string, index) {
+            decoded.push((index, char));
+            index = next;
+        }
+        assert_eq!(decoded, string.char_indices().collect::<std::vec::Vec<_>>());
+    }
+
+    /// Captures the `LEN` and `STR` of a string literal's `TypeStr`.
+    #[derive(Clone, Copy)]
+    struct Measured {
+        len: usize,
+        string: &'static str,
+        bytes: &'static [u8],
+    }
+
+    impl<Str: TypeStr> FromLiteralStr<Str> for Measured {
+        const VALID_LITERAL: &'static str = {
+            assert!(Str::LEN == Str::STR.len());
+            Str::STR
+        };
+
+        fn into_self() -> Self {
+            Measured {
+                len: Str::LEN,
+                string: <Self as FromLiteralStr<Str>>::VALID_LITERAL,
+                bytes: Str::BYTES,
+            }
+        }
+    }
+
+    #[test]
+    #[overloaded_literals]
+    fn type_str_len_is_the_number_of_bytes() {
+        let empty: Measured = "";
+        let ascii: Measured = "hello";
+        let unicode: Measured = "λ🦀";
+        for measured in [empty, ascii, unicode] {
+            assert_eq!(measured.len, measured.string.len());
+        }
+        assert_eq!(unicode.len, 6);
+    }
+
+    #[test]
+    #[overloaded_literals]
+    fn type_str_bytes_are_the_bytes_of_the_str() {
+        let empty: Measured = "";
+        let ascii: Measured = "hello";
+        let unicode: Measured = "λ🦀";
+        for measured in [empty, ascii, unicode] {
+            assert_eq!(measured.bytes, measured.string.as_bytes());
+        }
+        assert_eq!(ascii.bytes, b"hello");
+    }
+
+    #[test]
+    fn const_str_eq_ignore_ascii_case_ignores_only_ascii_case() {
+        use crate::type_str::const_str_eq_ignore_ascii_case;
+
+        assert!(const_str_eq_ignore_ascii_case("hello", "hello"));
+        assert!(const_str_eq_ignore_ascii_case("Hello", "hello"));
+        assert!(const_str_eq_ignore_ascii_case("HELLO", "hElLo"));
+        assert!(const_str_eq_ignore_ascii_case("", ""));
+        assert!(!const_str_eq_ignore_ascii_case("hello", "hell"));
+        assert!(!const_str_eq_ignore_ascii_case("hello", "help!"));
+        // Only ASCII letters are compared case-insensitively
+        assert!(!const_str_eq_ignore_ascii_case("Λ", "λ"));
+        assert!(!const_str_eq_ignore_ascii_case("@", "`"));
+    }
+
+    #[test]
+    fn try_from_value_integers() {
+        assert_eq!(u8::try_from_value(255u128), Ok(255));
+        assert_eq!(u8::try_from_value(256u128), Err(LiteralError::OutOfRange));
+        assert_eq!(i8::try_from_value(127u128), Ok(127));
+        assert_eq!(i8::try_from_value(128u128), Err(LiteralError::OutOfRange));
+        assert_eq!(i8::try_from_value(-128i128), Ok(-128));
+        assert_eq!(i8::try_from_value(-129i128), Err(LiteralError::OutOfRange));
+
+        let nonzero = |value: i128| NonZeroI16::try_from_value(value);
+        assert_eq!(nonzero(-5).map(NonZeroI16::get), Ok(-5));
+        assert_eq!(nonzero(0), Err(LiteralError::Zero));
+        assert_eq!(nonzero(-32769), Err(LiteralError::OutOfRange));
+
+        let wrapping = |value: u128| Wrapping::<NonZeroU8>::try_from_value(value);
+        assert_eq!(wrapping(7).map(|w| w.0.get()), Ok(7));
+        assert_eq!(wrapping(0), Err(LiteralError::Zero));
+
+        let ordering = |value: i128| Ordering::try_from_value(value);
+        assert_eq!(
+            (ordering(-1), ordering(1)),
+            (Ok(Ordering::Less), Ok(Ordering::Greater))
+        );
+        assert_eq!(ordering(-2), Err(LiteralError::OutOfRange));
+    }
+
+    #[test]
+    fn try_from_value_strings() {
+        #[cfg(feature = "alloc")]
+        {
+            let runtime = alloc::string::String::from("runtime");
+            assert_eq!(<&str>::try_from_value(runtime.as_str()), Ok("runtime"));
+            assert_eq!(
+                alloc::string::String::try_from_value(runtime.as_str()),
+                Ok(runtime.clone())
+            );
+        }
+
+        let c_str = |value| <&CStr>::try_from_value(value);
+        assert_eq!(c_str("abc\0").map(CStr::to_bytes), Ok(&b"abc"[..]));
+        assert_eq!(c_str("abc"), Err(LiteralError::InvalidStr));
+        assert_eq!(c_str("a\0bc\0"), Err(LiteralError::InvalidStr));
+
+        #[cfg(feature = "std")]
+        {
+            let ip = |value| std::net::Ipv4Addr::try_from_value(value);
+            assert_eq!(ip("10.0.0.1").map(|ip| ip.octets()), Ok([10, 0, 0, 1]));
+            assert_eq!(ip("10.0.0.256"), Err(LiteralError::InvalidStr));
+        }
+    }
+
+    #[test]
+    fn try_from_value_other_literal_kinds() {
+        assert_eq!(bool::try_from_value(true), Ok(true));
+        assert_eq!(char::try_from_value('λ'), Ok('λ'));
+        assert_eq!(u8::try_from_value(b'A'), Ok(65));
+        assert_eq!(f64::try_from_value(1.5), Ok(1.5));
+        assert_eq!(f32::try_from_value(0.25), Ok(0.25));
+
+        let bytes: &[u8] = &[1, 2, 3];
+        assert_eq!(<&[u8]>::try_from_value(bytes), Ok(bytes));
+        assert_eq!(<&[u8; 3]>::try_from_value(bytes), Ok(&[1, 2, 3]));
+        assert_eq!(
+            <&[u8; 2]>::try_from_value(bytes),
+            Err(LiteralError::InvalidByteStr)
+        );
+
+        let debug = |value: u128| LiteralDebug::<NonZeroU8>::try_from_value(value);
+        assert_eq!(
+            debug(0x0f).map(|value| value.literal()),
+            Ok(SourceLiteral::Unsigned(15))
+        );
+        assert_eq!(debug(0).err(), Some(LiteralError::Zero));
+    }
+
+    #[test]
+    fn try_from_value_derived() {
+        #[derive(Debug, PartialEq, FromLiteralUnsigned)]
+        #[range(1..=100)]
+        struct Percentage(u8);
+
+        #[derive(Debug, PartialEq, FromLiteralUnsigned)]
+        enum Level {
+            Low = 1,
+            High = 10,
+        }
+
+        #[derive(Debug, PartialEq, FromLiteralStr)]
+        enum Answer {
+            #[literal("yes", "y")]
+            Yes,
+            #[literal("no")]
+            No,
+        }
+
+        #[derive(Debug, PartialEq, FromLiteralUnsigned)]
+        #[range(1..=340282366920938463463374607431768211455)]
+        struct Id(u128);
+
+        let id = |value: u128| Id::try_from_value(value);
+        assert_eq!(id(u128::MAX), Ok(Id(u128::MAX)));
+        assert_eq!(id(0), Err(LiteralError::OutOfRange));
+        let max_id: Id =
+            FromLiteralUnsigned::<340282366920938463463374607431768211455>::into_self();
+        assert_eq!(max_id, Id(u128::MAX));
+
+        let percentage = |value: u128| Percentage::try_from_value(value);
+        assert_eq!(percentage(100), Ok(Percentage(100)));
+        assert_eq!(percentage(0), Err(LiteralError::OutOfRange));
+        assert_eq!(percentage(101), Err(LiteralError::OutOfRange));
+
+        let level = |value: u128| Level::try_from_value(value);
+        assert_eq!((level(1), level(10)), (Ok(Level::Low), Ok(Level::High)));
+        assert_eq!(level(5), Err(LiteralError::OutOfRange));
+
+        let answer = |value: &str| Answer::try_from_value(value);
+        assert_eq!(
+            (answer("y"), answer("no")),
+            (Ok(Answer::Yes), Ok(Answer::No))
+        );
+        assert_eq!(answer("maybe"), Err(LiteralError::InvalidStr));
+    }
+
     // #[test]
     // #[overloaded_literals]
     // pub fn cstr_example() {
@@ -0,0 +1,282 @@
+use crate::{
+    FromLiteralBool, FromLiteralByte, FromLiteralByteStr, FromLiteralChar, FromLiteralFloat,
+    FromLiteralSigned, FromLiteralStr, FromLiteralUnsigned, LiteralError, TryFromLiteralValue,
+    TypeByteStr, TypeFloat, TypeStr,
+};
+use core::fmt;
+use core::ops::Deref;
+
+/// The literal a [LiteralDebug] was built from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SourceLiteral {
+    Unsigned(u128),
+    Signed(i128),
+    Float(f64),
+    Str(&'static str),
+    ByteStr(&'static [u8]),
+    Bool(bool),
+    Char(char),
+    Byte(u8),
+}
+
+/// Formats the literal the way it could have been written in the source, like `42`, `-1.5`, `"hello"`, `b"\xFF"`, `'a'` or `b'a'`.
+impl fmt::Display for SourceLiteral {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SourceLiteral::Unsigned(val) => write!(f, "{val}"),
+            SourceLiteral::Signed(val) => write!(f, "{val}"),
+            SourceLiteral::Float(val) => write!(f, "{val:?}"),
+            SourceLiteral::Str(val) => write!(f, "{val:?}"),
+            SourceLiteral::ByteStr(val) => write!(f, "b\"{}\"", val.escape_ascii()),
+            SourceLiteral::Bool(val) => write!(f, "{val}"),
+            SourceLiteral::Char(val) => write!(f, "{val:?}"),
+            SourceLiteral::Byte(val) => write!(f, "b'{}'", core::ascii::escape_default(*val)),
+        }
+    }
+}
+
+/// Wrapper which remembers the literal a value was built from, to show it when `Debug`-formatting the value.
+///
+/// `LiteralDebug<T>` implements each of the `FromLiteral*` traits that `T` implements,
+/// performing the exact same compile-time validation.
+/// It derefs to `T`, and [into_inner](LiteralDebug::into_inner) unwraps the value again.
+///
+/// Useful for tests and diagnostics of DSLs built on top of the literal traits,
+/// where the same value can be written in several ways.
+///
+/// ```rust
+/// use overloaded_literals::{literal_map, overloaded_literals, LiteralDebug};
+/// use std::num::NonZeroU8;
+///
+/// literal_map! {
+///     struct Greeting: &'static str {
+///         "hello" => "Hello, world!",
+///     }
+/// }
+///
+/// #[overloaded_literals]
+/// fn example() {
+///     let greeting: LiteralDebug<Greeting> = "hello";
+///     assert_eq!(format!("{greeting:?}"), r#"Greeting("hello") (from "hello")"#);
+///     assert_eq!(greeting.value(), "Hello, world!");
+///
+///     let mask: LiteralDebug<NonZeroU8> = 0x0f;
+///     assert_eq!(format!("{mask:?}"), "15 (from 15)");
+///     assert_eq!(mask.into_inner().get(), 15);
+/// }
+/// example()
+/// ```
+///
+/// Integer literals are remembered by value, so `0x0f` shows up as `15`.
+#[derive(Clone, Copy, PartialEq)]
+pub struct LiteralDebug<T> {
+    value: T,
+    literal: SourceLiteral,
+}
+
+impl<T> LiteralDebug<T> {
+    /// The literal the value was built from.
+    pub fn literal(&self) -> SourceLiteral {
+        self.literal
+    }
+
+    /// Unwraps the value, forgetting the literal.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> Deref for LiteralDebug<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for LiteralDebug<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} (from {})", self.value, self.literal)
+    }
+}
+
+impl<T: FromLiteralUnsigned<LIT>, const LIT: u128> FromLiteralUnsigned<LIT> for LiteralDebug<T> {
+    const VALID_LITERAL: u128 = T::VALID_LITERAL;
+
+    #[inline]
+    fn into_self() -> Self {
+        LiteralDebug {
+            value: T::into_self(),
+            literal: SourceLiteral::Unsigned(LIT),
+        }
+    }
+}
+
+impl<T: FromLiteralSigned<LIT>, const LIT: i128> FromLiteralSigned<LIT> for LiteralDebug<T> {
+    const VALID_LITERAL: i128 = T::VALID_LITERAL;
+
+    #[inline]
+    fn into_self() -> Self {
+        LiteralDebug {
+            value: T::into_self(),
+            literal: SourceLiteral::Signed(LIT),
+        }
+    }
+}
+
+impl<T: FromLiteralFloat<TFloat>, TFloat: TypeFloat> FromLiteralFloat<TFloat> for LiteralDebug<T> {
+    const VALID_LITERAL: f64 = T::VALID_LITERAL;
+
+    #[inline]
+    fn into_self() -> Self {
+        LiteralDebug {
+            value: T::into_self(),
+            literal: SourceLiteral::Float(TFloat::FLOAT),
+        }
+    }
+}
+
+impl<T: FromLiteralStr<Str>, Str: TypeStr> FromLiteralStr<Str> for LiteralDebug<T> {
+    const VALID_LITERAL: &'static str = T::VALID_LITERAL;
+
+    #[inline]
+    fn into_self() -> Self {
+        LiteralDebug {
+            value: T::into_self(),
+            literal: SourceLiteral::Str(Str::STR),
+        }
+    }
+}
+
+impl<T: FromLiteralByteStr<Bytes>, Bytes: TypeByteStr> FromLiteralByteStr<Bytes>
+    for LiteralDebug<T>
+{
+    const VALID_LITERAL: &'static [u8] = T::VALID_LITERAL;
+
+    #[inline]
+    fn into_self() -> Self {
+        LiteralDebug {
+            value: T::into_self(),
+            literal: SourceLiteral::ByteStr(Bytes::BYTES),
+        }
+    }
+}
+
+impl<T: FromLiteralBool<LIT>, const LIT: bool> FromLiteralBool<LIT> for LiteralDebug<T> {
+    const VALID_LITERAL: bool = T::VALID_LITERAL;
+
+    #[inline]
+    fn into_self() -> Self {
+        LiteralDebug {
+            value: T::into_self(),
+            literal: SourceLiteral::Bool(LIT),
+        }
+    }
+}
+
+impl<T: FromLiteralChar<LIT>, const LIT: char> FromLiteralChar<LIT> for LiteralDebug<T> {
+    const VALID_LITERAL: char = T::VALID_LITERAL;
+
+    #[inline]
+    fn into_self() -> Self {
+        LiteralDebug {
+            value: T::into_self(),
+            literal: SourceLiteral::Char(LIT),
+        }
+    }
+}
+
+impl<T: FromLiteralByte<LIT>, const LIT: u8> FromLiteralByte<LIT> for LiteralDebug<T> {
+    const VALID_LITERAL: u8 = T::VALID_LITERAL;
+
+    #[inline]
+    fn into_self() -> Self {
+        LiteralDebug {
+            value: T::into_self(),
+            literal: SourceLiteral::Byte(LIT),
+        }
+    }
+}
+
+/// Remembers `value` as the literal, just like [into_self](FromLiteralUnsigned::into_self) remembers the literal itself.
+impl<T: TryFromLiteralValue<u128>> TryFromLiteralValue<u128> for LiteralDebug<T> {
+    #[inline]
+    fn try_from_value(value: u128) -> Result<Self, LiteralError> {
+        T::try_from_value(value).map(|inner| LiteralDebug {
+            value: inner,
+            literal: SourceLiteral::Unsigned(value),
+        })
+    }
+}
+
+impl<T: TryFromLiteralValue<i128>> TryFromLiteralValue<i128> for LiteralDebug<T> {
+    #[inline]
+    fn try_from_value(value: i128) -> Result<Self, LiteralError> {
+        T::try_from_value(value).map(|inner| LiteralDebug {
+            value: inner,
+            literal: SourceLiteral::Signed(value),
+        })
+    }
+}
+
+impl<T: TryFromLiteralValue<f64>> TryFromLiteralValue<f64> for LiteralDebug<T> {
+    #[inline]
+    fn try_from_value(value: f64) -> Result<Self, LiteralError> {
+        T::try_from_value(value).map(|inner| LiteralDebug {
+            value: inner,
+            literal: SourceLiteral::Float(value),
+        })
+    }
+}
+
+/// Only for `&'static str`, since [SourceLiteral] keeps the string itself.
+impl<T: TryFromLiteralValue<&'static str>> TryFromLiteralValue<&'static str> for LiteralDebug<T> {
+    #[inline]
+    fn try_from_value(value: &'static str) -> Result<Self, LiteralError> {
+        T::try_from_value(value).map(|inner| LiteralDebug {
+            value: inner,
+            literal: SourceLiteral::Str(value),
+        })
+    }
+}
+
+/// Only for `&'static [u8]`, since [SourceLiteral] keeps the byte string itself.
+impl<T: TryFromLiteralValue<&'static [u8]>> TryFromLiteralValue<&'static [u8]> for LiteralDebug<T> {
+    #[inline]
+    fn try_from_value(value: &'static [u8]) -> Result<Self, LiteralError> {
+        T::try_from_value(value).map(|inner| LiteralDebug {
+            value: inner,
+            literal: SourceLiteral::ByteStr(value),
+        })
+    }
+}
+
+impl<T: TryFromLiteralValue<bool>> TryFromLiteralValue<bool> for LiteralDebug<T> {
+    #[inline]
+    fn try_from_value(value: bool) -> Result<Self, LiteralError> {
+        T::try_from_value(value).map(|inner| LiteralDebug {
+            value: inner,
+            literal: SourceLiteral::Bool(value),
+        })
+    }
+}
+
+impl<T: TryFromLiteralValue<char>> TryFromLiteralValue<char> for LiteralDebug<T> {
+    #[inline]
+    fn try_from_value(value: char) -> Result<Self, LiteralError> {
+        T::try_from_value(value).map(|inner| LiteralDebug {
+            value: inner,
+            literal: SourceLiteral::Char(value),
+        })
+    }
+}
+
+impl<T: TryFromLiteralValue<u8>> TryFromLiteralValue<u8> for LiteralDebug<T> {
+    #[inline]
+    fn try_from_value(value: u8) -> Result<Self, LiteralError> {
+        T::try_from_value(value).map(|inner| LiteralDebug {
+            value: inner,
+            literal: SourceLiteral::Byte(value),
+        })
+    }
+}
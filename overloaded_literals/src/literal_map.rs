@@ -0,0 +1,119 @@
+/// Defines a type which can only be built from one of a fixed set of string literal keys,
+/// each of which is associated with a value.
+///
+/// The generated type implements [FromLiteralStr](crate::FromLiteralStr),
+/// so using a string literal which is not one of the keys results in a compile error.
+/// It also implements [TryFromLiteralValue](crate::TryFromLiteralValue) for any `&str`,
+/// to look up a key which is only known at runtime.
+///
+/// Each value is an expression of the declared value type.
+/// It is evaluated (at runtime) every time [value()](#method.value) is called,
+/// so it can also be a call to a constructor.
+///
+/// The generated type wraps the `&'static str` key.
+/// It derives `Debug`, `Clone`, `Copy`, `PartialEq`, `Eq`, `PartialOrd`, `Ord` and `Hash`,
+/// and has:
+/// - a `KEYS` constant listing all keys, in the order they were declared
+/// - a `key()` method returning the key it was built from
+/// - a `value()` method returning the value associated with that key
+///
+/// ```rust
+/// use overloaded_literals::{literal_map, overloaded_literals};
+/// use std::num::NonZeroU32;
+///
+/// literal_map! {
+///     /// A unit of time, in seconds.
+///     pub struct TimeUnit: NonZeroU32 {
+///         "s" => NonZeroU32::new(1).unwrap(),
+///         "min" => NonZeroU32::new(60).unwrap(),
+///         "h" => NonZeroU32::new(60 * 60).unwrap(),
+///     }
+/// }
+///
+/// #[overloaded_literals]
+/// fn example() {
+///     let unit: TimeUnit = "min";
+///     assert_eq!(unit.key(), "min");
+///     assert_eq!(unit.value().get(), 60);
+///     assert_eq!(TimeUnit::KEYS, ["s", "min", "h"]);
+///     // let oops: TimeUnit = "day"; // <- This would cause a compile error :-)
+/// }
+/// example();
+///
+/// use overloaded_literals::{LiteralError, TryFromLiteralValue};
+/// let input = String::from("h");
+/// assert_eq!(TimeUnit::try_from_value(input.as_str()).map(|unit| unit.key()), Ok("h"));
+/// assert_eq!(TimeUnit::try_from_value("day"), Err(LiteralError::InvalidStr));
+/// ```
+///
+/// ```compile_fail
+/// use overloaded_literals::{literal_map, overloaded_literals};
+///
+/// literal_map! {
+///     struct Greeting: &'static str {
+///         "hello" => "Hello, world!",
+///         "goodbye" => "See you later!",
+///     }
+/// }
+///
+/// #[overloaded_literals]
+/// fn example() {
+///     let greeting: Greeting = "hi";
+/// }
+/// example()
+/// ```
+#[macro_export]
+macro_rules! literal_map {
+    ($(#[$attr:meta])* $vis:vis struct $name:ident: $value:ty { $($key:literal => $val:expr),* $(,)? }) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        $vis struct $name(&'static str);
+
+        impl $name {
+            /// All keys, in the order they were declared.
+            pub const KEYS: &'static [&'static str] = &[$($key),*];
+
+            /// The key this was built from.
+            pub fn key(&self) -> &'static str {
+                self.0
+            }
+
+            /// The value associated with the key.
+            pub fn value(&self) -> $value {
+                match self.0 {
+                    $($key => $val,)*
+                    _ => unreachable!(),
+                }
+            }
+        }
+
+        impl<Str: $crate::TypeStr> $crate::FromLiteralStr<Str> for $name {
+            const VALID_LITERAL: &'static str = {
+                let mut index = 0;
+                while index < $name::KEYS.len() && !$crate::type_str::const_str_eq($name::KEYS[index], Str::STR) {
+                    index += 1;
+                }
+                assert!(
+                    index < $name::KEYS.len(),
+                    concat!("Unknown ", stringify!($name), " literal, expected one of:", $(" `", $key, "`"),*)
+                );
+                Str::STR
+            };
+
+            #[inline]
+            fn into_self() -> Self {
+                $name(<Self as $crate::FromLiteralStr<Str>>::VALID_LITERAL)
+            }
+        }
+
+        impl<'a> $crate::TryFromLiteralValue<&'a str> for $name {
+            #[inline]
+            fn try_from_value(value: &'a str) -> ::core::result::Result<Self, $crate::LiteralError> {
+                match $name::KEYS.iter().find(|key| **key == value) {
+                    ::core::option::Option::Some(key) => ::core::result::Result::Ok($name(key)),
+                    ::core::option::Option::None => ::core::result::Result::Err($crate::LiteralError::InvalidStr),
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,44 @@
+use crate::LiteralError;
+
+/// Build your datatype from a value which is only known at runtime (like one read from a config file),
+/// with the same validation as a literal.
+///
+/// The `VALID_LITERAL` check of the `FromLiteral*` traits only runs at compile time,
+/// so it cannot be reused for this directly.
+/// Implementations should accept exactly the values which would be accepted as a literal,
+/// and return a [LiteralError] for any other value.
+///
+/// `Value` is the type of the literal kind which is accepted:
+///
+/// | Literal trait | `Value` |
+/// |---|---|
+/// | [FromLiteralUnsigned](crate::FromLiteralUnsigned) | `u128` |
+/// | [FromLiteralSigned](crate::FromLiteralSigned) | `i128` |
+/// | [FromLiteralFloat](crate::FromLiteralFloat) | `f64` |
+/// | [FromLiteralStr](crate::FromLiteralStr) | `&str` |
+/// | [FromLiteralByteStr](crate::FromLiteralByteStr) | `&[u8]` |
+/// | [FromLiteralBool](crate::FromLiteralBool) | `bool` |
+/// | [FromLiteralChar](crate::FromLiteralChar) | `char` |
+/// | [FromLiteralByte](crate::FromLiteralByte) | `u8` |
+///
+/// This is implemented for the types in this crate which implement the literal traits,
+/// as well as for the types defined by [literal_map!](crate::literal_map), [validated_str!](crate::validated_str)
+/// and the derive macros.
+///
+/// ```rust
+/// use core::ffi::CStr;
+/// use core::num::NonZeroU8;
+/// use overloaded_literals::{LiteralError, TryFromLiteralValue};
+///
+/// let count: u128 = 42;
+/// assert_eq!(NonZeroU8::try_from_value(count).map(NonZeroU8::get), Ok(42));
+/// assert_eq!(NonZeroU8::try_from_value(0u128), Err(LiteralError::Zero));
+///
+/// let name = "Dragon fruit\0";
+/// assert!(<&CStr>::try_from_value(name).is_ok());
+/// assert_eq!(<&CStr>::try_from_value("Ba\0nana\0"), Err(LiteralError::InvalidStr));
+/// ```
+pub trait TryFromLiteralValue<Value>: Sized {
+    /// Builds the value, or returns why `value` would not have been accepted as a literal.
+    fn try_from_value(value: Value) -> Result<Self, LiteralError>;
+}
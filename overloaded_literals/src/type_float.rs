@@ -29,10 +29,12 @@ pub trait TypeFloat: Sealed {
 }
 
 impl<const FLOAT_BITS: u64> TypeFloat for Float<FLOAT_BITS> {
+    #[allow(unknown_lints, unnecessary_transmutes)]
     const FLOAT: f64 = {
         // SAFETY: This is a const version of f64::from_bits()
         // c.f. https://doc.rust-lang.org/std/primitive.f64.html#method.from_bits
         // (Since NaN literals are not possible, this is 100% portable and safe)
+        // NOTE: `f64::from_bits` itself is only `const` since Rust 1.83, which is above our MSRV.
         unsafe { core::mem::transmute(FLOAT_BITS) }
     };
 }
@@ -8,7 +8,10 @@
 //! However, **consider the contents of this module an implementation detail,
 //! and do not depend on these details directly in your code. They are subject to change**.
 //!
-//! The only API which can be considered public and is guaranteed, is [TypeStr::STR].
+//! The only API which can be considered public and is guaranteed, is [TypeStr::STR], [TypeByteStr::BYTES]
+//! (which every `TypeStr` has as well) and the length `LEN` of both (see [TypeStr#length]),
+//! as well as the helper functions [const_str_eq], [const_str_eq_ignore_ascii_case], [is_valid_utf8] and [next_char]
+//! and the concatenation of two type-level strings, [Concat].
 use crate::sealed::Sealed;
 use tlist::{TCons, TList, TNil};
 
@@ -40,6 +43,69 @@ pub const MAX_STR_LIT_LEN: usize = 32768;
 ///
 /// Objects of this trait are automatically generated by the [overloaded_literals](macro@crate::overloaded_literals) macro.
 ///
+/// # Length
+/// The length of the string in bytes (so equal to `STR.len()`) is available as the associated constant `LEN`,
+/// which `TypeStr` gets from its [trait@TList] supertrait.
+/// It is part of the **stable API** as well, and is cheap to use in a `VALID_LITERAL` implementation:
+///
+/// ```rust
+/// use overloaded_literals::{overloaded_literals, FromLiteralStr, TypeStr};
+///
+/// /// A color like `"#ff8800"`.
+/// pub struct Color(&'static str);
+///
+/// impl<Str: TypeStr> FromLiteralStr<Str> for Color {
+///     const VALID_LITERAL: &'static str = {
+///         assert!(Str::LEN == 7, "Color has to be exactly seven characters long");
+///         Str::STR
+///     };
+///
+///     fn into_self() -> Self {
+///         Color(<Self as FromLiteralStr<Str>>::VALID_LITERAL)
+///     }
+/// }
+///
+/// #[overloaded_literals]
+/// fn example() {
+///     let orange: Color = "#ff8800";
+///     assert_eq!(orange.0, "#ff8800");
+/// }
+/// example()
+/// ```
+///
+/// # Bytes
+/// Every `TypeStr` is also a [TypeByteStr], so the string is available as a `&'static [u8]` as well, using `BYTES`.
+/// This is handy for byte-level validation in a `VALID_LITERAL` implementation:
+///
+/// ```rust
+/// use overloaded_literals::{overloaded_literals, FromLiteralStr, TypeStr};
+///
+/// /// A string of hexadecimal digits, like `"deadbeef"`.
+/// pub struct HexDigits(&'static str);
+///
+/// impl<Str: TypeStr> FromLiteralStr<Str> for HexDigits {
+///     const VALID_LITERAL: &'static str = {
+///         let mut index = 0;
+///         while index < Str::BYTES.len() {
+///             assert!(Str::BYTES[index].is_ascii_hexdigit(), "HexDigits can only contain hexadecimal digits");
+///             index += 1;
+///         }
+///         Str::STR
+///     };
+///
+///     fn into_self() -> Self {
+///         HexDigits(<Self as FromLiteralStr<Str>>::VALID_LITERAL)
+///     }
+/// }
+///
+/// #[overloaded_literals]
+/// fn example() {
+///     let digits: HexDigits = "deadbeef";
+///     assert_eq!(digits.0, "deadbeef");
+/// }
+/// example()
+/// ```
+///
 /// # How it works
 /// _This information is not important for normal use of the library. It is only interesting if you want to work on the internals of the library, or are curious._
 ///
@@ -59,7 +125,7 @@ pub const MAX_STR_LIT_LEN: usize = 32768;
 /// TCons<Byte<104>, TCons<Byte<101>, TCons<Byte<108>, TCons<Byte<108>, TCons<Byte<111>, TNil>>>>>
 /// ```
 ///
-/// The two implementations of the [TypeStr] trait (for [TNil] and [TCons]) then join together these u8 values
+/// The two implementations of the [TypeByteStr] trait (for [TNil] and [TCons]) then join together these u8 values
 /// from each of the elements in the type-level linked list,
 /// putting them in a large const array of fixed size.
 /// Finally, the prefix of this large const array is returned as a &'static [u8] slice cast to a &'static str.
@@ -67,24 +133,60 @@ pub const MAX_STR_LIT_LEN: usize = 32768;
 /// We need to use a large const array *of fixed size* because:
 /// - The array type in the implementation cannot depend on the generic const type parameter. (blocking feature: `generic_const_exprs`)
 /// - Memory allocation is also of course not possible in const context.
-pub trait TypeStr: TList + Sealed {
-    #[doc(hidden)]
-    const V: [u8; MAX_STR_LIT_LEN];
-
+///
+/// Since a [Byte] can hold any byte, a list built by hand could contain a malformed multi-byte sequence.
+/// So `STR` checks that the bytes are valid UTF-8 (using [is_valid_utf8]), and fails to compile otherwise:
+///
+/// ```compile_fail
+/// use overloaded_literals::TypeStr;
+/// use overloaded_literals::type_str::Byte;
+/// use tlist::TList;
+///
+/// // 0xC3 starts a two-byte sequence, but nothing follows it.
+/// const BROKEN: &str = <TList![Byte<b'a'>, Byte<0xC3>] as TypeStr>::STR;
+/// ```
+pub trait TypeStr: TypeByteStr {
     /// Turns the TypeStr into its const `&'static str` equivalent.
     /// This associated constant is part of the **stable API** of `TypeStr`.
     const STR: &'static str = {
+        assert!(
+            is_valid_utf8(Self::BYTES),
+            "TypeStr has to contain valid UTF-8"
+        );
+        // SAFETY: Checked just above.
+        unsafe { core::str::from_utf8_unchecked(Self::BYTES) }
+    };
+}
+
+impl TypeStr for TNil {}
+
+impl<First: ContainsByte, Rest: TypeStr> TypeStr for TCons<First, Rest> {}
+
+/// Trait to work with byte strings at the type level, allowing `&'static [u8]` in 'const generic' contexts.
+///
+/// Objects of this trait are automatically generated by the [overloaded_literals](macro@crate::overloaded_literals) macro
+/// for byte string literals like `b"hello"`.
+///
+/// A byte string is built from the very same type-level list of [Byte]s as a [TypeStr] (see there for how it works),
+/// except that its bytes do not need to be valid UTF-8.
+/// So only read out its bytes using [TypeByteStr::BYTES].
+pub trait TypeByteStr: TList + Sealed {
+    #[doc(hidden)]
+    const V: [u8; MAX_STR_LIT_LEN];
+
+    /// Turns the TypeByteStr into its const `&'static [u8]` equivalent.
+    /// This associated constant is part of the **stable API** of `TypeByteStr`.
+    const BYTES: &'static [u8] = {
         let ptr = &Self::V as *const u8;
-        let slice = unsafe { core::slice::from_raw_parts::<'static, u8>(ptr, Self::LEN) };
-        unsafe { core::str::from_utf8_unchecked(slice) }
+        unsafe { core::slice::from_raw_parts::<'static, u8>(ptr, Self::LEN) }
     };
 }
 
-impl TypeStr for TNil {
+impl TypeByteStr for TNil {
     const V: [u8; MAX_STR_LIT_LEN] = { [0; MAX_STR_LIT_LEN] };
 }
 
-impl<First: ContainsByte, Rest: TypeStr> TypeStr for TCons<First, Rest> {
+impl<First: ContainsByte, Rest: TypeByteStr> TypeByteStr for TCons<First, Rest> {
     const V: [u8; MAX_STR_LIT_LEN] = {
         assert!(Self::LEN <= MAX_STR_LIT_LEN);
 
@@ -102,10 +204,262 @@ impl<First: ContainsByte, Rest: TypeStr> TypeStr for TCons<First, Rest> {
         // This goes [u8; MAX_STR_LIT_LEN] -> *const [u8; MAX_STR_LIT_LEN] -> *const u8 -> *mut u8
         #[allow(unused_mut)]
         let mut arr: [u8; MAX_STR_LIT_LEN] = [0; MAX_STR_LIT_LEN];
-        let target_ptr = unsafe { core::mem::transmute(core::ptr::addr_of!(arr) as *const u8) }; // <- Poor man's addr_of_mut!
+        let target_ptr = unsafe {
+            core::mem::transmute::<*const u8, *mut u8>(core::ptr::addr_of!(arr) as *const u8)
+        }; // <- Poor man's addr_of_mut!
 
         unsafe { core::ptr::copy_nonoverlapping(first_elem_ptr, target_ptr, 1) };
         unsafe { core::ptr::copy_nonoverlapping(rest_ptr, target_ptr.add(1), Rest::LEN) };
         arr
     };
 }
+
+/// The concatenation of the type-level strings `Lhs` and `Rhs`, which is a [TypeStr] (or [TypeByteStr]) itself.
+///
+/// Useful to build a string from a literal in a `FromLiteralStr` implementation, like a prefixed identifier.
+/// When `Lhs` is a fixed string, the concatenation with the (generic) literal `Rhs` is a `TypeStr` without further bounds:
+///
+/// ```rust
+/// use overloaded_literals::{overloaded_literals, FromLiteralStr, TypeStr};
+/// use overloaded_literals::type_str::{Byte, Concat};
+/// use tlist::TList;
+///
+/// /// The `app_` prefix, as a type-level string.
+/// type AppPrefix = TList![Byte<b'a'>, Byte<b'p'>, Byte<b'p'>, Byte<b'_'>];
+///
+/// /// A table name, which is always prefixed with `app_`.
+/// pub struct TableName(&'static str);
+///
+/// impl<Str: TypeStr> FromLiteralStr<Str> for TableName {
+///     const VALID_LITERAL: &'static str = Str::STR;
+///
+///     fn into_self() -> Self {
+///         TableName(<Concat<AppPrefix, Str> as TypeStr>::STR)
+///     }
+/// }
+///
+/// #[overloaded_literals]
+/// fn example() {
+///     let users: TableName = "users";
+///     assert_eq!(users.0, "app_users");
+/// }
+/// example()
+/// ```
+///
+/// Like any `TypeStr`, the concatenation can be at most [MAX_STR_LIT_LEN] bytes long.
+/// The type is part of the **stable API**, as is the `STR` (or `BYTES`) of the result.
+pub type Concat<Lhs, Rhs> = tlist::Concat<Lhs, Rhs>;
+
+/// Compares two strings for equality in const context,
+/// where `==` on `&str` cannot be used (yet) on stable Rust.
+///
+/// Useful to compare a literal against a fixed set of allowed values in a `VALID_LITERAL` implementation:
+///
+/// ```rust
+/// use overloaded_literals::{overloaded_literals, FromLiteralStr, TypeStr};
+/// use overloaded_literals::type_str::const_str_eq;
+///
+/// /// One of the log levels `"error"`, `"warn"` or `"info"`.
+/// pub struct LogLevel(&'static str);
+///
+/// impl<Str: TypeStr> FromLiteralStr<Str> for LogLevel {
+///     const VALID_LITERAL: &'static str = {
+///         assert!(
+///             const_str_eq(Str::STR, "error") || const_str_eq(Str::STR, "warn") || const_str_eq(Str::STR, "info"),
+///             "LogLevel has to be one of `error`, `warn` or `info`"
+///         );
+///         Str::STR
+///     };
+///
+///     fn into_self() -> Self {
+///         LogLevel(<Self as FromLiteralStr<Str>>::VALID_LITERAL)
+///     }
+/// }
+///
+/// #[overloaded_literals]
+/// fn example() {
+///     let level: LogLevel = "warn";
+///     assert_eq!(level.0, "warn");
+/// }
+/// example()
+/// ```
+///
+/// It can also be used for plain constants:
+///
+/// ```rust
+/// use overloaded_literals::type_str::const_str_eq;
+///
+/// const SAME: bool = const_str_eq("hello", "hello");
+/// const DIFFERENT: bool = const_str_eq("hello", "help!");
+/// assert!(SAME);
+/// assert!(!DIFFERENT);
+/// ```
+pub const fn const_str_eq(lhs: &str, rhs: &str) -> bool {
+    if lhs.len() != rhs.len() {
+        return false;
+    }
+    let len = lhs.len();
+    let lhs_bytes = lhs.as_bytes();
+    let rhs_bytes = rhs.as_bytes();
+    let mut index = 0;
+    while index < len {
+        if lhs_bytes[index] != rhs_bytes[index] {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
+
+/// Compares two strings for equality in const context, ignoring the case of ASCII letters,
+/// just like [str::eq_ignore_ascii_case].
+///
+/// Useful for a `VALID_LITERAL` implementation which accepts both `"hello"` and `"HELLO"`:
+///
+/// ```rust
+/// use overloaded_literals::type_str::const_str_eq_ignore_ascii_case;
+///
+/// const SAME: bool = const_str_eq_ignore_ascii_case("Hello", "hELLO");
+/// const DIFFERENT: bool = const_str_eq_ignore_ascii_case("Hello", "Help!");
+/// assert!(SAME);
+/// assert!(!DIFFERENT);
+/// ```
+///
+/// See [const_str_eq] for a full example.
+pub const fn const_str_eq_ignore_ascii_case(lhs: &str, rhs: &str) -> bool {
+    if lhs.len() != rhs.len() {
+        return false;
+    }
+    let len = lhs.len();
+    let lhs_bytes = lhs.as_bytes();
+    let rhs_bytes = rhs.as_bytes();
+    let mut index = 0;
+    while index < len {
+        if !lhs_bytes[index].eq_ignore_ascii_case(&rhs_bytes[index]) {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
+
+/// Checks in const context whether the given bytes are valid UTF-8,
+/// following the same rules as [core::str::from_utf8]
+/// (so overlong encodings, surrogates and codepoints above `U+10FFFF` are rejected).
+///
+/// [TypeStr::STR] uses this to make sure its bytes form a valid `&str`.
+///
+/// ```rust
+/// use overloaded_literals::type_str::is_valid_utf8;
+///
+/// const VALID: bool = is_valid_utf8("héllo, wörld 🦀".as_bytes());
+/// const TRUNCATED: bool = is_valid_utf8(b"\xF0\x9F\xA6");
+/// const SURROGATE: bool = is_valid_utf8(b"\xED\xA0\x80");
+/// assert!(VALID);
+/// assert!(!TRUNCATED);
+/// assert!(!SURROGATE);
+/// ```
+pub const fn is_valid_utf8(bytes: &[u8]) -> bool {
+    let mut index = 0;
+    while index < bytes.len() {
+        let first = bytes[index];
+        // The allowed range of the second byte, and the number of bytes in the sequence.
+        let (second_min, second_max, width) = match first {
+            0x00..=0x7F => {
+                index += 1;
+                continue;
+            }
+            0xC2..=0xDF => (0x80, 0xBF, 2),
+            0xE0 => (0xA0, 0xBF, 3),
+            0xED => (0x80, 0x9F, 3),
+            0xE1..=0xEC | 0xEE..=0xEF => (0x80, 0xBF, 3),
+            0xF0 => (0x90, 0xBF, 4),
+            0xF1..=0xF3 => (0x80, 0xBF, 4),
+            0xF4 => (0x80, 0x8F, 4),
+            _ => return false,
+        };
+        if index + width > bytes.len() {
+            return false;
+        }
+        let second = bytes[index + 1];
+        if second < second_min || second > second_max {
+            return false;
+        }
+        let mut offset = 2;
+        while offset < width {
+            if bytes[index + offset] & 0xC0 != 0x80 {
+                return false;
+            }
+            offset += 1;
+        }
+        index += width;
+    }
+    true
+}
+
+/// Decodes the char starting at byte `index` of `string` in const context,
+/// returning it together with the index of the char after it,
+/// or `None` once `index` reaches the end of the string.
+///
+/// This makes it possible to iterate over the chars of a string in a `VALID_LITERAL` implementation
+/// (where `str::chars` cannot be used on stable Rust), to check their character classes:
+///
+/// ```rust
+/// use overloaded_literals::{overloaded_literals, FromLiteralStr, TypeStr};
+/// use overloaded_literals::type_str::next_char;
+///
+/// /// A word of Greek letters, like `"λόγος"`.
+/// pub struct GreekWord(&'static str);
+///
+/// impl<Str: TypeStr> FromLiteralStr<Str> for GreekWord {
+///     const VALID_LITERAL: &'static str = {
+///         let mut index = 0;
+///         while let Some((char, next)) = next_char(Str::STR, index) {
+///             assert!(
+///                 matches!(char, '\u{0370}'..='\u{03FF}' | '\u{1F00}'..='\u{1FFF}'),
+///                 "GreekWord can only contain Greek letters"
+///             );
+///             index = next;
+///         }
+///         Str::STR
+///     };
+///
+///     fn into_self() -> Self {
+///         GreekWord(<Self as FromLiteralStr<Str>>::VALID_LITERAL)
+///     }
+/// }
+///
+/// #[overloaded_literals]
+/// fn example() {
+///     let word: GreekWord = "λόγος";
+///     assert_eq!(word.0, "λόγος");
+/// }
+/// example()
+/// ```
+///
+/// # Panics
+/// When `index` is past the end of the string, or not on a char boundary.
+pub const fn next_char(string: &str, index: usize) -> Option<(char, usize)> {
+    let bytes = string.as_bytes();
+    if index == bytes.len() {
+        return None;
+    }
+    let first = bytes[index];
+    let (mut codepoint, width) = match first {
+        0x00..=0x7F => return Some((first as char, index + 1)),
+        0xC0..=0xDF => ((first & 0x1F) as u32, 2),
+        0xE0..=0xEF => ((first & 0x0F) as u32, 3),
+        0xF0..=0xF7 => ((first & 0x07) as u32, 4),
+        _ => panic!("index is not on a char boundary"),
+    };
+    let mut offset = 1;
+    while offset < width {
+        codepoint = (codepoint << 6) | (bytes[index + offset] & 0x3F) as u32;
+        offset += 1;
+    }
+    // SAFETY: `string` is valid UTF-8, so the sequence starting at a char boundary decodes to a valid char.
+    // (`char::from_u32` is not a `const fn` yet on the supported Rust versions.)
+    #[allow(unknown_lints, unnecessary_transmutes)]
+    let char = unsafe { core::mem::transmute::<u32, char>(codepoint) };
+    Some((char, index + width))
+}
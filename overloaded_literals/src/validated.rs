@@ -0,0 +1,108 @@
+/// Defines a string newtype whose literals are checked by a `const fn(&str) -> bool` validator.
+///
+/// This is a shorthand for writing a struct wrapping a `&'static str`
+/// together with a [FromLiteralStr](crate::FromLiteralStr) implementation
+/// that only accepts literals for which the validator returns `true`.
+///
+/// Ideally this would be a single type `Validated<const F: fn(&str) -> bool>`,
+/// but function pointers are not allowed as const generic parameters
+/// on any Rust toolchain (stable or nightly), so a separate type is defined per validator instead.
+///
+/// The validator has to be a `const fn`, since it is called at compile-time.
+/// Calling it with the generic `TStr::STR` works on every supported toolchain (Rust 1.65 and up).
+///
+/// The generated type derives `Debug`, `Clone`, `Copy`, `PartialEq`, `Eq`, `PartialOrd`, `Ord` and `Hash`,
+/// and has an `as_str()` method returning the wrapped string.
+/// It also implements [TryFromLiteralValue](crate::TryFromLiteralValue) for `&'static str`,
+/// calling the same validator at runtime.
+///
+/// ```rust
+/// use overloaded_literals::{overloaded_literals, validated_str};
+///
+/// const fn is_lowercase(str: &str) -> bool {
+///     let bytes = str.as_bytes();
+///     let mut index = 0;
+///     while index < bytes.len() {
+///         if bytes[index].is_ascii_uppercase() {
+///             return false;
+///         }
+///         index += 1;
+///     }
+///     true
+/// }
+///
+/// validated_str! {
+///     /// A string without any uppercase ASCII letters.
+///     pub struct Lowercase = is_lowercase;
+/// }
+///
+/// #[overloaded_literals]
+/// fn example() {
+///     let val: Lowercase = "abc";
+///     assert_eq!(val.as_str(), "abc");
+///     // let oops: Lowercase = "ABC"; // <- This would cause a compile error :-)
+/// }
+/// example();
+///
+/// use overloaded_literals::{LiteralError, TryFromLiteralValue};
+/// assert_eq!(Lowercase::try_from_value("xyz").map(|val| val.as_str()), Ok("xyz"));
+/// assert_eq!(Lowercase::try_from_value("XYZ"), Err(LiteralError::InvalidStr));
+/// ```
+///
+/// ```compile_fail
+/// use overloaded_literals::{overloaded_literals, validated_str};
+///
+/// const fn is_short(str: &str) -> bool {
+///     str.len() <= 4
+/// }
+///
+/// validated_str! {
+///     struct Short = is_short;
+/// }
+///
+/// #[overloaded_literals]
+/// fn example() {
+///     let val: Short = "much too long";
+/// }
+/// example()
+/// ```
+#[macro_export]
+macro_rules! validated_str {
+    ($(#[$attr:meta])* $vis:vis struct $name:ident = $validator:path;) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        $vis struct $name(&'static str);
+
+        impl $name {
+            pub fn as_str(&self) -> &'static str {
+                self.0
+            }
+        }
+
+        impl<Str: $crate::TypeStr> $crate::FromLiteralStr<Str> for $name {
+            const VALID_LITERAL: &'static str = {
+                assert!(
+                    $validator(Str::STR),
+                    concat!("Invalid ", stringify!($name), " literal: rejected by `", stringify!($validator), "`")
+                );
+                Str::STR
+            };
+
+            #[inline]
+            fn into_self() -> Self {
+                $name(<Self as $crate::FromLiteralStr<Str>>::VALID_LITERAL)
+            }
+        }
+
+        impl $crate::TryFromLiteralValue<&'static str> for $name {
+            #[inline]
+            fn try_from_value(value: &'static str) -> ::core::result::Result<Self, $crate::LiteralError> {
+                if $validator(value) {
+                    ::core::result::Result::Ok($name(value))
+                } else {
+                    ::core::result::Result::Err($crate::LiteralError::InvalidStr)
+                }
+            }
+        }
+    };
+}
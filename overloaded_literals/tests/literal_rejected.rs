@@ -0,0 +1,585 @@
+//! Tests asserting that invalid literals are rejected at compile-time, with a particular error message.
+//!
+//! Use the [assert_literal_rejected] macro to add a new case:
+//!
+//! ```ignore
+//! #[test]
+//! #[ignore = "builds a crate per case; run with `--ignored`"]
+//! fn nonzero_zero() {
+//!     assert_literal_rejected!("let x: NonZeroU8 = 0;", "NonZero integer literal was 0");
+//! }
+//! ```
+//!
+//! The given code is used as the body of a `#[overloaded_literals] fn main()`,
+//! in a small crate depending on `overloaded_literals` which is generated and built for each case.
+//! Everything from `core::num`, `core::ffi::CStr` and `overloaded_literals::examples` is in scope.
+//! The case passes if building fails, and the compiler output contains the given message.
+//! Add `features = ["alloc"]` to enable features of `overloaded_literals` in the generated crate.
+//! Use [assert_error_points_at] to check that the error points at the offending literal.
+//!
+//! Since building a crate per case is slow, these tests are `#[ignore]`d by a plain `cargo test`.
+//! Run them with `cargo test --test literal_rejected -- --ignored` (as CI does).
+//!
+//! (Doctests with a `compile_fail` annotation are lighter-weight,
+//! but they cannot check _why_ compilation failed.)
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+const PRELUDE: &str = "\
+#![allow(unused)]
+use core::ffi::CStr;
+use core::num::*;
+use overloaded_literals::examples::*;
+use overloaded_literals::overloaded_literals;
+";
+
+/// Builds `code` as the body of an overloaded `main` function,
+/// and asserts that this fails with an error containing `message`.
+macro_rules! assert_literal_rejected {
+    ($code:expr, $message:expr $(,)?) => {
+        assert_literal_rejected(&format!("line_{}", line!()), $code, $message, &[])
+    };
+    ($code:expr, $message:expr, features = [$($feature:literal),*] $(,)?) => {
+        assert_literal_rejected(&format!("line_{}", line!()), $code, $message, &[$($feature),*])
+    };
+}
+
+fn assert_literal_rejected(name: &str, code: &str, message: &str, features: &[&str]) {
+    let output = build_case(name, code, features);
+    assert!(
+        !output.status.success(),
+        "Expected the literal in `{code}` to be rejected, but it compiled successfully"
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains(message),
+        "Expected the error for `{code}` to contain {message:?}, but it was:\n{stderr}"
+    );
+}
+
+fn build_case(name: &str, code: &str, features: &[&str]) -> std::process::Output {
+    let base = Path::new(env!("CARGO_TARGET_TMPDIR")).join("literal_rejected");
+    let dir = base.join(name);
+    fs::create_dir_all(dir.join("src")).unwrap();
+    let manifest = format!(
+        r#"[package]
+name = "literal_rejected_{name}"
+version = "0.0.0"
+edition = "2021"
+publish = false
+
+[dependencies]
+overloaded_literals = {{ path = {lib_path:?}, features = {features:?} }}
+tlist = "0.7.0"
+
+[workspace]
+"#,
+        lib_path = env!("CARGO_MANIFEST_DIR"),
+    );
+    fs::write(dir.join("Cargo.toml"), manifest).unwrap();
+    let main = format!("{PRELUDE}\n#[overloaded_literals]\nfn main() {{\n{code}\n}}\n");
+    fs::write(dir.join("src").join("main.rs"), main).unwrap();
+    // Re-use the workspace's lockfile (if any) so dependency versions match
+    // and no registry access is needed.
+    let lockfile = Path::new(env!("CARGO_MANIFEST_DIR")).join("../Cargo.lock");
+    if lockfile.exists() && !dir.join("Cargo.lock").exists() {
+        fs::copy(lockfile, dir.join("Cargo.lock")).unwrap();
+    }
+
+    // NOTE: `cargo check` is not enough, as errors in const evaluation of associated consts
+    // are only reported once the code is monomorphized.
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    Command::new(cargo)
+        .arg("build")
+        .arg("--quiet")
+        .current_dir(&dir)
+        .env("CARGO_TARGET_DIR", base.join("target"))
+        .env("CARGO_TERM_COLOR", "never")
+        .output()
+        .unwrap()
+}
+
+/// Asserts that the error for `code` points at the (first) occurrence of `literal` in it,
+/// by checking there is a note with its location in the generated `src/main.rs`.
+fn assert_error_points_at(name: &str, code: &str, literal: &str) {
+    let output = build_case(name, code, &[]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    // `code` starts on the line after the `fn main() {` which follows the prelude
+    let line = PRELUDE.lines().count() + 4;
+    let column = code.find(literal).unwrap() + 1;
+    let location = format!("src/main.rs:{line}:{column}");
+    assert!(
+        stderr.contains(&location),
+        "Expected the error for `{code}` to point at {location}, but it was:\n{stderr}"
+    );
+}
+
+#[test]
+#[ignore = "builds a crate per case; run with `--ignored`"]
+fn unsigned_out_of_range() {
+    assert_literal_rejected!(
+        "let x: u8 = 256;",
+        "Out of range integer literal for u8 (0..=255)"
+    );
+}
+
+#[test]
+#[ignore = "builds a crate per case; run with `--ignored`"]
+fn signed_out_of_range() {
+    assert_literal_rejected!(
+        "let x: i8 = -129;",
+        "Out of range integer literal for i8 (-128..=127)"
+    );
+}
+
+#[test]
+#[ignore = "builds a crate per case; run with `--ignored`"]
+fn signed_out_of_range_positive() {
+    assert_literal_rejected!(
+        "let x: i8 = 128;",
+        "Out of range integer literal for i8 (-128..=127)"
+    );
+    assert_literal_rejected!(
+        "let x: i16 = 32768;",
+        "Out of range integer literal for i16 (-32768..=32767)"
+    );
+    assert_literal_rejected!(
+        "let x: i32 = 2147483648;",
+        "Out of range integer literal for i32 (-2147483648..=2147483647)"
+    );
+    assert_literal_rejected!(
+        "let x: i64 = 9223372036854775808;",
+        "Out of range integer literal for i64 (-9223372036854775808..=9223372036854775807)"
+    );
+    assert_literal_rejected!(
+        "let x: i128 = 170141183460469231731687303715884105728;",
+        "Out of range integer literal for i128"
+    );
+    #[cfg(target_pointer_width = "16")]
+    assert_literal_rejected!(
+        "let x: isize = 32768;",
+        "Out of range integer literal for isize (isize::MIN..=isize::MAX)"
+    );
+    #[cfg(target_pointer_width = "32")]
+    assert_literal_rejected!(
+        "let x: isize = 2147483648;",
+        "Out of range integer literal for isize (isize::MIN..=isize::MAX)"
+    );
+    #[cfg(target_pointer_width = "64")]
+    assert_literal_rejected!(
+        "let x: isize = 9223372036854775808;",
+        "Out of range integer literal for isize (isize::MIN..=isize::MAX)"
+    );
+}
+
+#[test]
+#[ignore = "builds a crate per case; run with `--ignored`"]
+fn error_points_at_the_literal() {
+    assert_error_points_at("span_annotated", "let x: u8 = 1024;", "1024");
+    assert_error_points_at(
+        "span_inferred",
+        "fn takes(port: Port) {} takes(70000);",
+        "70000",
+    );
+}
+
+#[test]
+#[ignore = "builds a crate per case; run with `--ignored`"]
+fn out_of_range_messages_name_the_type_and_range() {
+    assert_literal_rejected!(
+        "let x: u32 = 4294967296;",
+        "Out of range integer literal for u32 (0..=4294967295)"
+    );
+    assert_literal_rejected!(
+        "let x: i16 = -32769;",
+        "Out of range integer literal for i16 (-32768..=32767)"
+    );
+    assert_literal_rejected!(
+        "let x: NonZeroU16 = 65536;",
+        "Out of range NonZero integer literal for NonZeroU16 (1..=65535)"
+    );
+    assert_literal_rejected!(
+        "let x: NonZeroI8 = -129;",
+        "Out of range NonZero integer literal for NonZeroI8 (-128..=127)"
+    );
+}
+
+#[test]
+#[ignore = "builds a crate per case; run with `--ignored`"]
+fn nonzero_signed_out_of_range_positive() {
+    assert_literal_rejected!(
+        "let x: NonZeroI8 = 128;",
+        "Out of range NonZero integer literal for NonZeroI8 (-128..=127)"
+    );
+    assert_literal_rejected!(
+        "let x: NonZeroI8 = 200;",
+        "Out of range NonZero integer literal for NonZeroI8 (-128..=127)"
+    );
+    assert_literal_rejected!(
+        "let x: NonZeroI128 = 170141183460469231731687303715884105728;",
+        "Out of range NonZero integer literal for NonZeroI128"
+    );
+}
+
+#[test]
+#[ignore = "builds a crate per case; run with `--ignored`"]
+fn nonzero_zero() {
+    assert_literal_rejected!("let x: NonZeroU8 = 0;", "NonZero integer literal was 0");
+}
+
+#[test]
+#[ignore = "builds a crate per case; run with `--ignored`"]
+fn tuple_elements_are_checked_by_position() {
+    assert_literal_rejected!(
+        "let pair: (u8, NonZeroU16) = (10, 0);",
+        "NonZero integer literal was 0"
+    );
+    assert_literal_rejected!(
+        "let pair: (u8, NonZeroU16) = (256, 20);",
+        "Out of range integer literal for u8 (0..=255)"
+    );
+}
+
+#[test]
+#[ignore = "builds a crate per case; run with `--ignored`"]
+fn returned_literals_are_checked() {
+    assert_literal_rejected!(
+        "fn early() -> NonZeroU8 { return 0; } early();",
+        "NonZero integer literal was 0"
+    );
+    assert_literal_rejected!(
+        "fn tail() -> NonZeroU8 { 0 } tail();",
+        "NonZero integer literal was 0"
+    );
+    assert_literal_rejected!(
+        "fn arms(x: u8) -> NonZeroU8 { match x { 0 => 1, _ => 0 } } arms(1);",
+        "NonZero integer literal was 0"
+    );
+}
+
+#[test]
+#[ignore = "builds a crate per case; run with `--ignored`"]
+fn arguments_are_checked_against_the_parameter_type() {
+    assert_literal_rejected!(
+        "fn retries(count: NonZeroU8) {} retries(0);",
+        "NonZero integer literal was 0"
+    );
+    assert_literal_rejected!(
+        "struct Server; impl Server { fn listen(&self, port: Port) {} } Server.listen(0);",
+        "Port cannot be 0"
+    );
+}
+
+#[test]
+#[ignore = "builds a crate per case; run with `--ignored`"]
+fn struct_fields_are_checked_against_their_declared_types() {
+    assert_literal_rejected!(
+        "struct Job { retries: NonZeroU8 } let job = Job { retries: 0 };",
+        "NonZero integer literal was 0"
+    );
+}
+
+#[test]
+#[ignore = "builds a crate per case; run with `--ignored`"]
+fn nonzero_repeated_zero() {
+    assert_literal_rejected!(
+        "let buf: [NonZeroU8; 4] = [0; 4];",
+        "NonZero integer literal was 0"
+    );
+}
+
+#[test]
+#[ignore = "builds a crate per case; run with `--ignored`"]
+fn nonzero_vec_element_zero() {
+    assert_literal_rejected!(
+        "let xs: Vec<NonZeroU8> = vec![1, 0];",
+        "NonZero integer literal was 0"
+    );
+    assert_literal_rejected!(
+        "let buf: Vec<NonZeroU8> = vec![0; 4];",
+        "NonZero integer literal was 0"
+    );
+}
+
+#[test]
+#[ignore = "builds a crate per case; run with `--ignored`"]
+fn nonzero_signed_zero() {
+    assert_literal_rejected!("let n: NonZeroI16 = 0;", "NonZero integer literal was 0");
+    assert_literal_rejected!("let n: NonZeroI16 = -0;", "NonZero integer literal was 0");
+    assert_literal_rejected!(
+        "let n: NonZeroI16 = -32769;",
+        "Out of range NonZero integer literal for NonZeroI16 (-32768..=32767)"
+    );
+}
+
+#[test]
+#[ignore = "builds a crate per case; run with `--ignored`"]
+fn cstr_missing_nul_terminator() {
+    assert_literal_rejected!(
+        r#"let x: &CStr = "hello";"#,
+        "nul-terminator missing for CStr"
+    );
+}
+
+#[test]
+#[ignore = "builds a crate per case; run with `--ignored`"]
+fn byte_array_of_a_different_length() {
+    assert_literal_rejected!(
+        "let x: &[u8; 2] = b\"abc\";",
+        "Byte string literal does not have the length of the array type"
+    );
+}
+
+#[test]
+#[ignore = "builds a crate per case; run with `--ignored`"]
+fn example_type() {
+    assert_literal_rejected!(
+        r#"let x: SqlIdent = "select";"#,
+        "SqlIdent cannot be a reserved SQL word"
+    );
+}
+
+#[test]
+#[ignore = "builds a crate per case; run with `--ignored`"]
+fn nonzero_usize_zero() {
+    assert_literal_rejected!("let x: NonZeroUsize = 0;", "NonZero integer literal was 0");
+}
+
+#[test]
+#[ignore = "builds a crate per case; run with `--ignored`"]
+fn nonzero_usize_out_of_range() {
+    #[cfg(target_pointer_width = "16")]
+    assert_literal_rejected!(
+        "let x: NonZeroUsize = 65536;",
+        "Out of range NonZero integer literal"
+    );
+    #[cfg(target_pointer_width = "32")]
+    assert_literal_rejected!(
+        "let x: NonZeroUsize = 4294967296;",
+        "Out of range NonZero integer literal"
+    );
+    #[cfg(target_pointer_width = "64")]
+    assert_literal_rejected!(
+        "let x: NonZeroUsize = 18446744073709551616;",
+        "Out of range NonZero integer literal for NonZeroUsize (1..=usize::MAX)"
+    );
+}
+
+#[test]
+#[ignore = "builds a crate per case; run with `--ignored`"]
+fn wrapping_usize_out_of_range() {
+    #[cfg(target_pointer_width = "16")]
+    assert_literal_rejected!(
+        "let x: Wrapping<usize> = 65536;",
+        "Out of range integer literal"
+    );
+    #[cfg(target_pointer_width = "32")]
+    assert_literal_rejected!(
+        "let x: Wrapping<usize> = 4294967296;",
+        "Out of range integer literal"
+    );
+    #[cfg(target_pointer_width = "64")]
+    assert_literal_rejected!(
+        "let x: Wrapping<usize> = 18446744073709551616;",
+        "Out of range integer literal for usize (0..=usize::MAX)"
+    );
+}
+
+#[test]
+#[ignore = "builds a crate per case; run with `--ignored`"]
+fn wrapping_isize_out_of_range() {
+    #[cfg(target_pointer_width = "16")]
+    assert_literal_rejected!(
+        "let x: Wrapping<isize> = -32769;",
+        "Out of range integer literal"
+    );
+    #[cfg(target_pointer_width = "32")]
+    assert_literal_rejected!(
+        "let x: Wrapping<isize> = -2147483649;",
+        "Out of range integer literal"
+    );
+    #[cfg(target_pointer_width = "64")]
+    assert_literal_rejected!(
+        "let x: Wrapping<isize> = -9223372036854775809;",
+        "Out of range integer literal for isize (isize::MIN..=isize::MAX)"
+    );
+}
+
+#[test]
+#[ignore = "builds a crate per case; run with `--ignored`"]
+fn validated_str_rejected() {
+    assert_literal_rejected!(
+        "const fn is_nonempty(str: &str) -> bool { !str.is_empty() }
+        overloaded_literals::validated_str! { struct NonEmpty = is_nonempty; }
+        let x: NonEmpty = \"\";",
+        "Invalid NonEmpty literal: rejected by `is_nonempty`"
+    );
+}
+
+#[test]
+#[ignore = "builds a crate per case; run with `--ignored`"]
+fn annotated_type_without_implementation() {
+    assert_literal_rejected!(
+        "let x: bool = 5;",
+        "the trait bound `bool: FromLiteralUnsigned<5>` is not satisfied"
+    );
+}
+
+#[test]
+#[ignore = "builds a crate per case; run with `--ignored`"]
+fn literal_map_unknown_key() {
+    assert_literal_rejected!(
+        "overloaded_literals::literal_map! { struct Unit: u32 { \"s\" => 1, \"min\" => 60 } }
+        let x: Unit = \"h\";",
+        "Unknown Unit literal, expected one of: `s` `min`"
+    );
+}
+
+#[test]
+#[ignore = "builds a crate per case; run with `--ignored`"]
+fn derived_str_enum_unknown_literal() {
+    assert_literal_rejected!(
+        r#"
+        #[derive(overloaded_literals::FromLiteralStr)]
+        enum Greeting {
+            #[literal("hello")]
+            Hello,
+            #[literal("goodbye")]
+            Goodbye,
+        }
+        let greeting: Greeting = "hey";
+        "#,
+        r#"Invalid Greeting literal: expected one of "hello", "goodbye""#
+    );
+}
+
+#[test]
+#[ignore = "builds a crate per case; run with `--ignored`"]
+fn derived_range_newtype_out_of_range() {
+    let percentage = "
+        #[derive(overloaded_literals::FromLiteralUnsigned)]
+        #[range(0..=100)]
+        struct Percentage(u8);
+    ";
+    assert_literal_rejected!(
+        &format!("{percentage} let x: Percentage = 101;"),
+        "Out of range Percentage (0..=100)"
+    );
+    // The field's own check still applies
+    assert_literal_rejected!(
+        "#[derive(overloaded_literals::FromLiteralUnsigned)]
+        #[range(1..)]
+        struct Count(u8);
+        let x: Count = 256;",
+        "Out of range integer literal for u8 (0..=255)"
+    );
+}
+
+#[test]
+#[ignore = "builds a crate per case; run with `--ignored`"]
+fn literal_debug_keeps_validation() {
+    assert_literal_rejected!(
+        "let x: overloaded_literals::LiteralDebug<std::num::NonZeroU8> = 0;",
+        "NonZero integer literal was 0"
+    );
+}
+
+#[test]
+#[ignore = "builds a crate per case; run with `--ignored`"]
+fn ascii_char_not_ascii() {
+    assert_literal_rejected!(
+        "let x: overloaded_literals::examples::AsciiChar = 'λ';",
+        "AsciiChar has to be an ASCII character"
+    );
+}
+
+#[test]
+#[ignore = "builds a crate per case; run with `--ignored`"]
+fn const_literal_rejected() {
+    assert_literal_rejected!(
+        "struct SizedBuffer<const SIZE: usize>;
+        let x = SizedBuffer::<{ overloaded_literals::const_literal!(overloaded_literals::examples::Capacity<256>, 0 as usize) }>;",
+        "Capacity has to be at least 1"
+    );
+}
+
+#[test]
+#[ignore = "builds a crate per case; run with `--ignored`"]
+fn hex_literal_out_of_range() {
+    assert_literal_rejected!(
+        "let x: Capacity<0xff> = 0x1_00;",
+        "Capacity cannot be larger than its maximum `MAX`"
+    );
+}
+
+#[test]
+#[ignore = "builds a crate per case; run with `--ignored`"]
+fn c_string_nul_in_the_middle() {
+    assert_literal_rejected!(
+        "let x: std::ffi::CString = \"Ba\\0nana\";",
+        "CString cannot contain nul bytes",
+        features = ["alloc"],
+    );
+}
+
+#[test]
+#[ignore = "builds a crate per case; run with `--ignored`"]
+fn ipv4_out_of_range() {
+    assert_literal_rejected!(
+        "let x: std::net::Ipv4Addr = \"192.168.0.256\";",
+        "Ipv4Addr literal part is larger than 255",
+        features = ["std"],
+    );
+}
+
+#[test]
+#[ignore = "builds a crate per case; run with `--ignored`"]
+fn ipv4_leading_zero() {
+    assert_literal_rejected!(
+        "let x: std::net::Ipv4Addr = \"192.168.01.1\";",
+        "Ipv4Addr literal part has a leading zero",
+        features = ["std"],
+    );
+}
+
+#[test]
+#[ignore = "builds a crate per case; run with `--ignored`"]
+fn ipv4_too_few_parts() {
+    assert_literal_rejected!(
+        "let x: std::net::Ipv4Addr = \"10.0.1\";",
+        "Ipv4Addr literal has fewer than four parts",
+        features = ["std"],
+    );
+}
+
+#[test]
+#[ignore = "builds a crate per case; run with `--ignored`"]
+fn ipv4_too_many_parts() {
+    assert_literal_rejected!(
+        "let x: std::net::Ipv4Addr = \"10.0.0.1.5\";",
+        "Ipv4Addr literal has more than four parts",
+        features = ["std"],
+    );
+}
+
+#[test]
+#[ignore = "builds a crate per case; run with `--ignored`"]
+fn ipv4_empty_part() {
+    assert_literal_rejected!(
+        "let x: std::net::Ipv4Addr = \"10..0.1\";",
+        "Ipv4Addr literal has an empty part",
+        features = ["std"],
+    );
+}
+
+#[test]
+#[ignore = "builds a crate per case; run with `--ignored`"]
+fn ipv4_not_a_digit() {
+    assert_literal_rejected!(
+        "let x: std::net::Ipv4Addr = \"10.0.0.x\";",
+        "Ipv4Addr literal can only contain digits and `.`",
+        features = ["std"],
+    );
+}
@@ -0,0 +1,1239 @@
+//! Tests for the positions in which the [overloaded_literals] macro rewrites literals,
+//! and for the target type being picked up correctly (either by the macro or by type inference).
+use overloaded_literals::examples::{
+    AsciiChar, MacAddress, Port, PrintableByte, SizeAlign, SqlIdent,
+};
+use overloaded_literals::{
+    overloaded_literals, FromLiteralBool, FromLiteralUnsigned, LiteralDebug, SourceLiteral,
+};
+use std::future::Future;
+use std::num::{NonZeroU16, NonZeroU32, NonZeroU8};
+use std::panic::Location;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+
+#[test]
+#[overloaded_literals]
+fn smart_pointer_constructor_arguments() {
+    let boxed: Box<NonZeroU8> = Box::new(5);
+    assert_eq!(boxed.get(), 5);
+
+    let rc: Rc<NonZeroU8> = Rc::new(6);
+    assert_eq!(rc.get(), 6);
+
+    let arc: Arc<SqlIdent> = Arc::new("users");
+    assert_eq!(arc.as_str(), "users");
+
+    let pinned: Pin<Box<NonZeroU8>> = Box::pin(7);
+    assert_eq!(pinned.get(), 7);
+}
+
+#[test]
+#[overloaded_literals]
+fn smart_pointer_constructor_arguments_inferred_from_usage() {
+    fn takes_arc(val: Arc<NonZeroU8>) -> u8 {
+        val.get()
+    }
+    let arc = Arc::new(8);
+    assert_eq!(takes_arc(arc), 8);
+}
+
+#[test]
+#[overloaded_literals]
+fn function_and_method_arguments_inferred_from_parameters() {
+    fn retries(count: NonZeroU8) -> u8 {
+        count.get()
+    }
+    struct Connection {
+        port: Port,
+    }
+    impl Connection {
+        fn with_port(self, port: Port) -> Self {
+            Connection { port }
+        }
+    }
+    let connection = Connection { port: 80 }.with_port(8080);
+    let three = retries(3);
+    let more = [retries(4), retries(5)];
+    assert_eq!(three, 3);
+    assert_eq!(more, [4, 5]);
+    assert_eq!(connection.port.get(), 8080);
+}
+
+#[test]
+#[overloaded_literals]
+fn struct_fields_inferred_from_their_declared_types() {
+    #[derive(Clone, Copy)]
+    struct Job {
+        retries: NonZeroU8,
+        table: SqlIdent,
+        priority: u8,
+    }
+    struct Pair(NonZeroU8, Port);
+    let job = Job {
+        retries: 5,
+        table: "jobs",
+        priority: 1,
+    };
+    let retried = Job { retries: 2, ..job };
+    let pair = Pair(3, 443);
+    assert_eq!(job.retries.get(), 5);
+    assert_eq!(job.table.as_str(), "jobs");
+    assert_eq!(retried.retries.get(), 2);
+    assert_eq!(retried.priority, 1);
+    assert_eq!((pair.0.get(), pair.1.get()), (3, 443));
+}
+
+#[test]
+#[overloaded_literals]
+fn unsafe_blocks() {
+    /// # Safety
+    /// Only here to require an `unsafe` block at the call site.
+    unsafe fn ffi_like(count: NonZeroU8, table: SqlIdent) -> (u8, &'static str) {
+        (count.get(), table.as_str())
+    }
+
+    let (count, table) = unsafe { ffi_like(3, "users") };
+    assert_eq!(count, 3);
+    assert_eq!(table, "users");
+
+    let inside: u8 = unsafe {
+        let (count, _) = ffi_like(4, "orders");
+        count
+    };
+    assert_eq!(inside, 4);
+}
+
+#[test]
+#[overloaded_literals]
+fn nested_closures() {
+    let table: SqlIdent = "users";
+    let make = move || {
+        move || {
+            let x: NonZeroU8 = 5;
+            (x, table)
+        }
+    };
+    let (x, table) = make()();
+    assert_eq!(x.get(), 5);
+    assert_eq!(table.as_str(), "users");
+
+    let adder = |offset: NonZeroU8| move || offset.saturating_add(10);
+    assert_eq!(adder(NonZeroU8::new(1).unwrap())().get(), 11);
+}
+
+#[test]
+#[overloaded_literals]
+fn closure_return_types() {
+    let five = |_x: u8| -> NonZeroU8 { 5 };
+    let either = |flag: bool| -> NonZeroU8 {
+        if flag {
+            1
+        } else {
+            2
+        }
+    };
+    let inferred = || {
+        let x: NonZeroU8 = 7;
+        x
+    };
+    assert_eq!(five(0).get(), 5);
+    assert_eq!((either(true).get(), either(false).get()), (1, 2));
+    assert_eq!(inferred().get(), 7);
+
+    // The closure's own annotation wins over the type of a shadowed variable
+    let x: u8 = 1;
+    let x: NonZeroU8 = NonZeroU8::new(x).unwrap();
+    let shadowed = |x: u8| -> SqlIdent {
+        let _ = x;
+        "orders"
+    };
+    assert_eq!(x.get(), 1);
+    assert_eq!(shadowed(2).as_str(), "orders");
+}
+
+#[test]
+#[overloaded_literals]
+fn nested_items() {
+    fn table() -> SqlIdent {
+        "users"
+    }
+
+    struct Retries(NonZeroU8);
+
+    impl Retries {
+        fn new() -> Self {
+            Retries(3)
+        }
+    }
+
+    #[overloaded_literals(skip)]
+    fn plain() -> u64 {
+        // Would be ambiguous if rewritten
+        1 << 40
+    }
+
+    assert_eq!(table().as_str(), "users");
+    assert_eq!(Retries::new().0.get(), 3);
+    assert_eq!(plain(), 1 << 40);
+}
+
+#[test]
+#[overloaded_literals]
+fn mutable_bindings() {
+    let mut x: NonZeroU8 = 5;
+    assert_eq!(x.get(), 5);
+    x = 6;
+    assert_eq!(x.get(), 6);
+
+    let (mut a, b): (NonZeroU8, SqlIdent) = (1, "users");
+    a = a.saturating_add(2);
+    assert_eq!(a.get(), 3);
+    assert_eq!(b.as_str(), "users");
+}
+
+#[test]
+#[overloaded_literals(macros)]
+fn dbg_macro_opt_in() {
+    let x: NonZeroU8 = dbg!(5);
+    assert_eq!(x.get(), 5);
+
+    let (count, table): (NonZeroU8, SqlIdent) = dbg!(6, "users");
+    assert_eq!(count.get(), 6);
+    assert_eq!(table.as_str(), "users");
+}
+
+#[test]
+#[overloaded_literals]
+fn macros_left_alone_by_default() {
+    let x: u32 = dbg!(5);
+    assert_eq!(x, 5);
+}
+
+#[test]
+#[overloaded_literals(macros, skip_macros(dbg))]
+fn skipped_macros() {
+    let x = dbg!(5);
+    let y: i32 = x;
+    assert_eq!(y, 5);
+}
+
+#[overloaded_literals]
+fn unit_returning() {
+    let x: NonZeroU8 = 5;
+    assert_eq!(x.get(), 5);
+}
+
+#[overloaded_literals]
+fn unit_returning_with_semicolon_tail() {
+    let x: NonZeroU8 = 5;
+    x.get();
+}
+
+#[test]
+fn unit_return_types() {
+    let () = unit_returning();
+    let () = unit_returning_with_semicolon_tail();
+}
+
+#[overloaded_literals]
+fn parse_retries(input: &str) -> Result<NonZeroU8, SqlIdent> {
+    match input {
+        "default" => Ok(3),
+        "none" => Err("no_retries"),
+        _ => {
+            if input.is_empty() {
+                return Ok(1);
+            }
+            Err("invalid")
+        }
+    }
+}
+
+#[overloaded_literals]
+fn retries_or_one(input: &str) -> Result<NonZeroU8, SqlIdent> {
+    let retries = parse_retries(input)?;
+    Ok(retries.saturating_add(1))
+}
+
+#[test]
+fn result_returning() {
+    assert_eq!(parse_retries("default").map(NonZeroU8::get), Ok(3));
+    assert_eq!(parse_retries("").map(NonZeroU8::get), Ok(1));
+    assert_eq!(
+        parse_retries("none").map_err(|err| err.as_str()),
+        Err("no_retries")
+    );
+    assert_eq!(retries_or_one("default").map(NonZeroU8::get), Ok(4));
+    assert_eq!(
+        retries_or_one("oops").map_err(|err| err.as_str()),
+        Err("invalid")
+    );
+}
+
+#[test]
+#[overloaded_literals(macros)]
+fn format_macros_opt_in() {
+    use std::fmt::Write;
+
+    // These only compile when the literals are rewritten:
+    fn table_name(table: SqlIdent) -> &'static str {
+        table.as_str()
+    }
+    fn double(x: NonZeroU8) -> NonZeroU8 {
+        x.saturating_add(x.get())
+    }
+
+    let formatted = format!("{}-{table}", double(2), table = table_name("users"));
+    assert_eq!(formatted, "4-users");
+
+    let mut out = String::new();
+    writeln!(out, "{:?}", double(3)).unwrap();
+    assert_eq!(out, "6\n");
+
+    println!("{}", double(4));
+}
+
+#[test]
+#[overloaded_literals]
+fn const_position_literals_alongside_overloaded_ones() {
+    let n: NonZeroU8 = 5;
+    // The element is overloaded (as a `u8`), the repeat length is left alone.
+    let arr: [u8; 10] = [0; 10];
+    let lengths: [usize; 3] = [arr.len(); 3];
+    assert_eq!(n.get(), 5);
+    assert_eq!(arr, [0; 10]);
+    assert_eq!(lengths, [10; 3]);
+}
+
+#[test]
+#[overloaded_literals]
+fn suffixed_literals_are_left_alone() {
+    // Unsuffixed, so overloaded:
+    let overloaded: NonZeroU8 = 42;
+    // Suffixed, so plain primitives.
+    // Were these rewritten, the method calls would fail with 'type annotations needed'.
+    let plain = 42i32;
+    let negative = -42i64;
+    let float = 2.25f64;
+    let bytes = [0u8; 4];
+    let small = 5u8;
+    let offset = -3i32;
+    let count = 100usize;
+    assert_eq!(overloaded.get(), 42);
+    assert_eq!(plain.count_ones(), 3);
+    assert_eq!(negative.abs(), 42);
+    assert_eq!(float.sqrt(), 1.5);
+    assert_eq!(bytes.len(), 4);
+    assert_eq!(small.leading_zeros(), 5);
+    assert_eq!(offset.signum(), -1);
+    assert_eq!(count.next_power_of_two(), 128);
+}
+
+#[test]
+#[overloaded_literals]
+fn annotated_types_are_named_in_the_call() {
+    let n: std::num::NonZeroU8 = 5;
+    let s: &'static str = "hello";
+    let c: &core::ffi::CStr = "hello\0";
+    let w: std::num::Wrapping<i8> = -128;
+    assert_eq!(n.get(), 5);
+    assert_eq!(s, "hello");
+    assert_eq!(c.to_bytes(), b"hello");
+    assert_eq!(w.0, i8::MIN);
+}
+
+#[test]
+#[overloaded_literals]
+fn if_and_match_initializers() {
+    let ns: [u8; 3] = [0, 1, 2];
+    for n in ns {
+        let c = n == 1;
+        let x: NonZeroU8 = if c { 1 } else { 2 };
+        let y: NonZeroU8 = match n {
+            0 => 10,
+            _ if c => {
+                let offset: u8 = 1;
+                NonZeroU8::new(offset.saturating_add(10)).unwrap()
+            }
+            _ => 12,
+        };
+        let z: SqlIdent = if c {
+            "users"
+        } else if n == 2 {
+            "orders"
+        } else {
+            "items"
+        };
+        assert_eq!(x.get(), if c { 1 } else { 2 });
+        assert_eq!(y.get(), 10 + n);
+        assert_eq!(z.as_str(), ["items", "users", "orders"][n as usize]);
+    }
+}
+
+/// Remembers where it was constructed.
+struct CallSite(&'static Location<'static>);
+
+impl<const LIT: u128> overloaded_literals::FromLiteralUnsigned<LIT> for CallSite {
+    const VALID_LITERAL: u128 = LIT;
+
+    fn into_self() -> Self {
+        CallSite(Location::caller())
+    }
+}
+
+#[test]
+#[overloaded_literals]
+fn let_else_initializers() {
+    let Ok(n): Result<NonZeroU8, ()> = Ok(5) else {
+        panic!("not reached");
+    };
+    let Some((table, count)): Option<(SqlIdent, NonZeroU8)> = Some(("users", 3)) else {
+        return;
+    };
+    // The `else` block is folded as well, but does not pick up the annotated type:
+    let Some(doubled): Option<NonZeroU8> = n.checked_mul(2) else {
+        let _unreachable: NonZeroU8 = 1;
+        return;
+    };
+    assert_eq!(n.get(), 5);
+    assert_eq!((table.as_str(), count.get()), ("users", 3));
+    assert_eq!(doubled.get(), 10);
+}
+
+#[test]
+#[overloaded_literals]
+fn loop_bounds_are_left_alone_by_default() {
+    let mut total: u32 = 0;
+    // Overloading the range bounds would leave them without an inferable type.
+    for i in 0..4 {
+        total = total.saturating_add(i * i);
+    }
+    let mut countdown: u8 = 3;
+    while countdown > 0 {
+        countdown = countdown.saturating_sub(1);
+    }
+    assert_eq!(total, 14);
+    assert_eq!(countdown, 0);
+}
+
+#[test]
+#[overloaded_literals]
+fn marked_loop_bounds_are_overloaded() {
+    let mut tries: NonZeroU8 = 1;
+    while tries
+        < #[overload]
+        3
+    {
+        tries = tries.saturating_add(1);
+    }
+    let mut seen: Vec<u8> = Vec::new();
+    for n in [
+        #[overload]
+        1,
+        #[overload]
+        2,
+    ] {
+        let n: NonZeroU8 = n;
+        seen.push(n.get());
+    }
+    assert_eq!(tries.get(), 3);
+    assert_eq!(seen, [1, 2]);
+}
+
+#[test]
+#[overloaded_literals]
+#[allow(clippy::redundant_closure_call)]
+fn closures_and_blocks_in_loop_bounds_and_indices() {
+    let mut seen: Vec<u8> = Vec::new();
+    for x in (0..2u8).map(|_| {
+        let y: NonZeroU8 = 5;
+        y
+    }) {
+        seen.push(x.get());
+    }
+    assert_eq!(seen, [5, 5]);
+
+    let mut n = 0u8;
+    while (|| {
+        let y: NonZeroU8 = 5;
+        y.get()
+    })() > n
+    {
+        n += 1u8;
+    }
+    assert_eq!(n, 5);
+
+    let a = [10u8, 20, 30];
+    let second = a[{
+        let t: NonZeroU8 = 1;
+        t.get() as usize
+    }];
+    assert_eq!(second, 20);
+}
+
+#[test]
+#[overloaded_literals]
+fn indices_are_left_alone() {
+    let tables: [SqlIdent; 2] = ["users", "orders"];
+    let first = tables[0];
+    let rest = &tables[1..];
+    let elems: &[NonZeroU8] = &[1, 2, 3][..2];
+    assert_eq!(first.as_str(), "users");
+    assert_eq!(rest.len(), 1);
+    assert_eq!(
+        elems,
+        [NonZeroU8::new(1).unwrap(), NonZeroU8::new(2).unwrap()]
+    );
+}
+
+#[test]
+#[overloaded_literals]
+fn skipped_statements_and_expressions() {
+    // These would be ambiguous if rewritten
+    #[overloaded_literals::skip]
+    let raw = 1 << 40;
+    let len = plain_usize(
+        #[overloaded_literals::skip]
+        "abc".len(),
+    );
+
+    let mut retries = 0u8;
+    let marked: LiteralDebug<NonZeroU8>;
+    #[overloaded_literals::skip]
+    {
+        retries += 1;
+        marked = #[overload]
+        3;
+    }
+    let table: SqlIdent = "users";
+
+    #[overloaded_literals::skip]
+    println!("{}", 1);
+    #[overloaded_literals::skip]
+    fn inner() -> usize {
+        "abc".len()
+    }
+
+    assert_eq!(plain_u64(raw), 1 << 40);
+    assert_eq!(len, 3);
+    assert_eq!(inner(), 3);
+    assert_eq!(retries, 1);
+    assert_eq!(marked.literal(), SourceLiteral::Unsigned(3));
+    assert_eq!(table.as_str(), "users");
+}
+
+fn plain_usize(value: usize) -> usize {
+    value
+}
+
+fn plain_u64(value: u64) -> u64 {
+    value
+}
+
+/// Runs a future which never has to wait to completion.
+fn block_on<F: Future>(future: F) -> F::Output {
+    struct NoopWaker;
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+    let waker = Waker::from(Arc::new(NoopWaker));
+    let mut context = Context::from_waker(&waker);
+    let mut future = Box::pin(future);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+#[overloaded_literals]
+async fn async_table() -> SqlIdent {
+    "users"
+}
+
+#[overloaded_literals]
+async fn async_blocks_and_await() -> (NonZeroU8, NonZeroU8, SqlIdent) {
+    let block = async {
+        let x: NonZeroU8 = 5;
+        x
+    }
+    .await;
+    let tail: NonZeroU8 = async { 6 }.await;
+    (block, tail, async_table().await)
+}
+
+#[test]
+fn async_blocks() {
+    let (block, tail, table) = block_on(async_blocks_and_await());
+    assert_eq!(block.get(), 5);
+    assert_eq!(tail.get(), 6);
+    assert_eq!(table.as_str(), "users");
+}
+
+/// A feature flag which can only be switched on.
+#[derive(Debug, PartialEq)]
+struct Enabled;
+
+impl<const LIT: bool> FromLiteralBool<LIT> for Enabled {
+    const VALID_LITERAL: bool = {
+        assert!(LIT, "feature flags can only be enabled");
+        LIT
+    };
+
+    fn into_self() -> Self {
+        Enabled
+    }
+}
+
+#[test]
+#[overloaded_literals]
+#[allow(clippy::nonminimal_bool)]
+fn bool_literals() {
+    let flag: Enabled = true;
+    let mut branches: Vec<u8> = Vec::new();
+    // Conditions are plain `bool`s; the `true`s and `false`s in them are left alone.
+    if true {
+        branches.push(1);
+    }
+    if !(false || branches.is_empty()) {
+        branches.push(2);
+    }
+    let matched: u8 = match branches.len() {
+        _ if false => 3,
+        _ if true && branches.len() == 2 => 4,
+        _ => 5,
+    };
+    assert_eq!(flag, Enabled);
+    assert_eq!(branches, [1, 2]);
+    assert_eq!(matched, 4);
+}
+
+#[test]
+#[overloaded_literals]
+fn char_literals() {
+    let plain: char = 'a';
+    let lambda: char = 'λ';
+    let crab: char = '🦀';
+    let escaped: char = '\u{10FFFF}';
+    let ascii: AsciiChar = '\n';
+    let debug: LiteralDebug<char> = 'λ';
+    assert_eq!(plain, 'a');
+    assert_eq!((lambda, lambda.len_utf8()), ('λ', 2));
+    assert_eq!((crab, crab.len_utf8()), ('🦀', 4));
+    assert_eq!(escaped, char::MAX);
+    assert_eq!(ascii.as_byte(), b'\n');
+    assert_eq!(format!("{debug:?}"), "'λ' (from 'λ')");
+}
+
+#[test]
+#[overloaded_literals]
+fn byte_literals() {
+    let plain: u8 = b'A';
+    let escaped: u8 = b'\xFF';
+    let printable: PrintableByte = b'~';
+    let debug: LiteralDebug<u8> = b'\n';
+    assert_eq!(plain, 65);
+    assert_eq!(escaped, u8::MAX);
+    assert_eq!(printable.as_char(), '~');
+    assert_eq!(format!("{debug:?}"), r"10 (from b'\n')");
+}
+
+#[test]
+#[overloaded_literals]
+fn byte_str_literals() {
+    let plain: &[u8] = b"hello";
+    let escaped: &'static [u8] = b"\x00\xFF\n";
+    let empty: &[u8] = b"";
+    let array: &[u8; 3] = b"abc";
+    let empty_array: &'static [u8; 0] = b"";
+    let mac: MacAddress = b"\x00\x1A\x2B\x3C\x4D\x5E";
+    let debug: LiteralDebug<&[u8]> = b"\xFF\"";
+    assert_eq!(plain, "hello".as_bytes());
+    assert_eq!(escaped, [0x00, 0xFF, 0x0A]);
+    assert!(empty.is_empty());
+    assert_eq!(array.map(char::from), ['a', 'b', 'c']);
+    assert!(empty_array.is_empty());
+    assert_eq!(mac.octets(), [0x00, 0x1A, 0x2B, 0x3C, 0x4D, 0x5E]);
+    assert_eq!(format!("{debug:?}"), r#"[255, 34] (from b"\xff\"")"#);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+#[overloaded_literals]
+fn byte_str_literals_into_vec() {
+    let mut owned: Vec<u8> = b"abc";
+    owned.push(b'd');
+    assert_eq!(owned, b"abcd".to_vec());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+#[overloaded_literals]
+fn owned_and_borrowed_strings() {
+    use std::borrow::Cow;
+
+    let mut owned: String = "hello";
+    owned.push('!');
+    let cow: Cow<'static, str> = "world";
+    assert_eq!(owned, "hello!");
+    assert!(matches!(cow, Cow::Borrowed("world")));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+#[overloaded_literals]
+fn c_strings() {
+    let owned: std::ffi::CString = "hello";
+    assert_eq!(owned.as_bytes_with_nul(), b"hello\0");
+}
+
+#[cfg(feature = "std")]
+#[test]
+#[overloaded_literals]
+fn path_literals() {
+    let hosts: std::path::PathBuf = "/etc/hosts";
+    let config: &std::path::Path = "config/app.toml";
+    assert_eq!(hosts.file_name().unwrap(), "hosts");
+    assert_eq!(config.extension().unwrap(), "toml");
+    assert!(hosts.is_absolute() && config.is_relative());
+}
+
+#[cfg(feature = "std")]
+#[test]
+#[overloaded_literals]
+fn ipv4_literals() {
+    use std::net::Ipv4Addr;
+
+    let local: Ipv4Addr = "127.0.0.1";
+    let any: Ipv4Addr = "0.0.0.0";
+    let broadcast: Ipv4Addr = "255.255.255.255";
+    assert_eq!(local, Ipv4Addr::LOCALHOST);
+    assert_eq!(any, Ipv4Addr::UNSPECIFIED);
+    assert_eq!(broadcast, Ipv4Addr::BROADCAST);
+}
+
+#[test]
+#[overloaded_literals]
+fn duration_units() {
+    use overloaded_literals::examples::{Millis, Secs};
+    use std::time::Duration;
+
+    let timeout: Millis = 1500;
+    let interval: Secs = 0;
+    assert_eq!(Duration::from(timeout), Duration::from_millis(1500));
+    assert_eq!(interval.duration(), Duration::ZERO);
+}
+
+#[test]
+#[overloaded_literals]
+fn literal_debug_remembers_the_literal() {
+    let signed: LiteralDebug<i8> = -5;
+    let float: LiteralDebug<f32> = 1.5;
+    let flag: LiteralDebug<bool> = true;
+    let ident: LiteralDebug<SqlIdent> = "users";
+    assert_eq!(signed.literal(), SourceLiteral::Signed(-5));
+    assert_eq!(format!("{signed:?}"), "-5 (from -5)");
+    assert_eq!(format!("{float:?}"), "1.5 (from 1.5)");
+    assert_eq!(format!("{flag:?}"), "true (from true)");
+    assert_eq!(format!("{ident:?}"), r#"SqlIdent("users") (from "users")"#);
+    assert_eq!(ident.as_str(), "users");
+}
+
+#[test]
+#[overloaded_literals]
+fn into_self_reports_the_location_of_the_literal() {
+    let (site, line): (CallSite, u32) = (42, line!());
+    assert_eq!(site.0.file(), file!());
+    assert_eq!(site.0.line(), line);
+    // The column of the literal itself rather than of the `let`:
+    assert_eq!(site.0.column(), 42);
+}
+
+#[test]
+#[overloaded_literals]
+fn annotated_tuples() {
+    let t: (NonZeroU8, SqlIdent) = (5, "users");
+    let nested: ((NonZeroU8, u16), &str) = ((1, 1000), "hello");
+    let pair: (u8, NonZeroU16) = (10, 20);
+    assert_eq!(t.0.get(), 5);
+    assert_eq!(t.1.as_str(), "users");
+    assert_eq!(nested.0 .0.get(), 1);
+    assert_eq!(nested.0 .1, 1000);
+    assert_eq!(nested.1, "hello");
+    assert_eq!(pair.0, 10);
+    assert_eq!(pair.1.get(), 20);
+}
+
+#[test]
+#[overloaded_literals]
+fn annotated_arrays() {
+    let xs: [NonZeroU8; 3] = [1, 2, 3];
+    let grid: [[NonZeroU8; 2]; 2] = [[1, 2], [3, 4]];
+    let idents: [SqlIdent; 2] = ["users", "orders"];
+    assert_eq!(xs.map(NonZeroU8::get), [1, 2, 3]);
+    assert_eq!(grid[1][0].get(), 3);
+    assert_eq!(idents[1].as_str(), "orders");
+}
+
+#[test]
+#[overloaded_literals]
+fn annotated_array_repeats() {
+    let buf: [NonZeroU8; 4] = [1; 4];
+    let rows: [[NonZeroU8; 2]; 3] = [[7; 2]; 3];
+    assert_eq!(buf.map(NonZeroU8::get), [1; 4]);
+    assert_eq!(rows[2][1].get(), 7);
+}
+
+#[test]
+#[overloaded_literals]
+fn annotated_vecs() {
+    let xs: Vec<NonZeroU8> = vec![1, 2, 3];
+    let buf: Vec<NonZeroU8> = vec![7; 4];
+    let grid: Vec<Vec<NonZeroU8>> = vec![vec![1, 2], vec![3]];
+    let idents: std::vec::Vec<SqlIdent> = vec!["users", "orders"];
+    let empty: Vec<NonZeroU8> = vec![];
+    assert_eq!(xs.iter().map(|x| x.get()).collect::<Vec<u8>>(), [1, 2, 3]);
+    assert_eq!(buf.len(), 4);
+    assert_eq!(buf[3].get(), 7);
+    assert_eq!(grid[1][0].get(), 3);
+    assert_eq!(idents[1].as_str(), "orders");
+    assert!(empty.is_empty());
+}
+
+#[test]
+#[overloaded_literals(macros)]
+fn vec_macro_opt_in() {
+    fn total(xs: Vec<NonZeroU8>) -> u32 {
+        xs.iter().map(|x| u32::from(x.get())).sum()
+    }
+    // Without an annotation, the element type is inferred from the parameter type
+    let xs = vec![1, 2, 3];
+    assert_eq!(total(xs), 6);
+}
+
+#[test]
+#[overloaded_literals]
+fn returned_literals_use_the_return_type() {
+    fn clamp_nonzero(n: u8) -> NonZeroU8 {
+        if n == 0 {
+            return 1;
+        }
+        match NonZeroU8::new(n) {
+            Some(nonzero) => nonzero,
+            None => 1,
+        }
+    }
+    fn default_port() -> Port {
+        8080
+    }
+    let early = |fallback: bool| -> SqlIdent {
+        if fallback {
+            return "fallback";
+        }
+        "users"
+    };
+    assert_eq!(clamp_nonzero(0).get(), 1);
+    assert_eq!(clamp_nonzero(3).get(), 3);
+    assert_eq!(default_port().get(), 8080);
+    assert_eq!(early(true).as_str(), "fallback");
+    assert_eq!(early(false).as_str(), "users");
+}
+
+#[overloaded_literals]
+fn retries_for(attempt: u8) -> NonZeroU8 {
+    match attempt {
+        0 => 5,
+        1 | 2 => {
+            if attempt == 1 {
+                3
+            } else {
+                2
+            }
+        }
+        _ => 1,
+    }
+}
+
+#[test]
+fn match_arms_use_the_return_type() {
+    let retries: Vec<u8> = (0..4).map(|attempt| retries_for(attempt).get()).collect();
+    assert_eq!(retries, [5, 3, 2, 1]);
+}
+
+#[test]
+#[overloaded_literals]
+fn type_positions_are_left_alone() {
+    struct Packet {
+        header: [u8; 4],
+        size: SizeAlign<8>,
+    }
+    fn first<const N: usize>(bytes: [u8; N]) -> Option<u8> {
+        bytes.first().copied()
+    }
+
+    let header: [u8; 4] = [1, 2, 3, 4];
+    let size: SizeAlign<8> = 16;
+    let packet = Packet { header, size };
+    let nested: [[u8; 2]; 3] = [[0; 2]; 3];
+    let sum = |pair: [u8; 2]| -> u8 { pair.iter().sum() };
+
+    assert_eq!(packet.header, [1, 2, 3, 4]);
+    assert_eq!(packet.size.size(), 16);
+    assert_eq!(nested.len(), 3);
+    assert_eq!(sum(nested[0]), 0);
+    assert_eq!(first::<4>(packet.header), Some(1));
+    assert_eq!(std::mem::size_of::<[u16; 8]>(), 16);
+}
+
+overloaded_literals::literal_map! {
+    struct Greeting: &'static str {
+        "hello" => "Hello, world!",
+        "goodbye" => "See you later!",
+    }
+}
+
+#[test]
+#[overloaded_literals]
+fn many_literal_kinds_in_one_body() {
+    use overloaded_literals::examples::{HexColor, Kib, Port, TimeOfDay};
+    use std::cmp::Ordering;
+    use std::ffi::CStr;
+    use std::num::{NonZeroI32, Wrapping};
+
+    let nonzero: NonZeroU8 = 5;
+    let greeting: Greeting = "hello";
+    let small: i8 = -100;
+    let table: SqlIdent = "users";
+    let port: Port = 8080;
+    // An unannotated literal in between must not pick up the type of the previous `let`:
+    let doubled = port.get().saturating_mul(2);
+    let negative: NonZeroI32 = -7;
+    let text: &str = "plain";
+    let c_str: &CStr = "nul terminated\0";
+    let color: HexColor = "#ff8800";
+    let time: TimeOfDay = "23:59";
+    let ratio: f64 = 0.25;
+    let negative_float: f32 = -1.5;
+    let flag: bool = true;
+    let ordering: Ordering = -1;
+    let wrapping: Wrapping<u8> = 255;
+    let size: Kib = 4;
+    let shadowed: NonZeroU8 = nonzero.saturating_add(1);
+    let nonzero: NonZeroU8 = 7;
+
+    assert_eq!(shadowed.get(), 6);
+    assert_eq!(nonzero.get(), 7);
+    assert_eq!(greeting.value(), "Hello, world!");
+    assert_eq!(small, -100);
+    assert_eq!(table.as_str(), "users");
+    assert_eq!(port.get(), 8080);
+    assert_eq!(doubled, 16160);
+    assert_eq!(negative.get(), -7);
+    assert_eq!(text, "plain");
+    assert_eq!(c_str.to_bytes(), b"nul terminated");
+    assert_eq!(color.rgb(), (0xff, 0x88, 0x00));
+    assert_eq!((time.hour(), time.minute()), (23, 59));
+    assert_eq!(ratio, 0.25);
+    assert_eq!(negative_float, -1.5);
+    assert!(flag);
+    assert_eq!(ordering, Ordering::Less);
+    assert_eq!((wrapping + Wrapping(1)).0, 0);
+    assert_eq!(size.bytes(), 4096);
+}
+
+#[overloaded_literals(int)]
+mod module_level {
+    use core::num::NonZeroU8;
+    use overloaded_literals::{examples::SqlIdent, overloaded_literals};
+
+    pub fn default_kinds() -> (NonZeroU8, usize) {
+        // Only integer literals are rewritten, so this string is left as a plain `&str`:
+        let message = "plain";
+        (5, message.len())
+    }
+
+    pub const LIMIT: NonZeroU8 = 8;
+    // Only integer literals are rewritten, so this stays a plain `&str` too:
+    pub static GREETING: &str = "hello";
+
+    pub struct Table;
+
+    impl Table {
+        pub const COLUMNS: NonZeroU8 = 4;
+
+        pub fn name() -> NonZeroU8 {
+            3
+        }
+    }
+
+    #[overloaded_literals(str)]
+    pub fn overridden() -> (SqlIdent, u8) {
+        // Only string literals are rewritten here, so this integer is left as a plain `u8`:
+        let count = 7u8;
+        ("users", count.saturating_add(0))
+    }
+}
+
+#[test]
+fn module_level_kinds_with_function_override() {
+    let (nonzero, len) = module_level::default_kinds();
+    assert_eq!((nonzero.get(), len), (5, 5));
+    assert_eq!(module_level::Table::name().get(), 3);
+    assert_eq!(module_level::LIMIT.get(), 8);
+    assert_eq!(module_level::GREETING, "hello");
+    assert_eq!(module_level::Table::COLUMNS.get(), 4);
+    let (table, count) = module_level::overridden();
+    assert_eq!((table.as_str(), count), ("users", 7));
+}
+
+struct Defaults;
+
+#[overloaded_literals]
+impl Defaults {
+    const ATTEMPTS: u32 = 3;
+    const LIMIT: NonZeroU8 = 10;
+    const TABLE: SqlIdent = "defaults";
+    const OFFSET: i8 = -4;
+
+    fn retries(&self) -> NonZeroU8 {
+        5
+    }
+
+    fn table(&self) -> SqlIdent {
+        "users"
+    }
+
+    fn mac(&self) -> MacAddress {
+        b"\x00\x1A\x2B\x3C\x4D\x5E"
+    }
+}
+
+#[test]
+fn impl_block_methods() {
+    assert_eq!(Defaults.retries().get(), 5);
+    assert_eq!(Defaults.table().as_str(), "users");
+    assert_eq!(Defaults.mac().octets()[5], 0x5E);
+    assert_eq!(Defaults::ATTEMPTS, 3);
+    assert_eq!(Defaults::LIMIT.get(), 10);
+    assert_eq!(Defaults::TABLE.as_str(), "defaults");
+    assert_eq!(Defaults::OFFSET, -4);
+}
+
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+#[overloaded_literals]
+fn describe_digit(digit: u8) -> SqlIdent {
+    match digit {
+        0 => "zero",
+        1 | 2 => "few",
+        3..=9 => "many",
+        _ => "invalid",
+    }
+}
+
+#[test]
+#[overloaded_literals]
+fn pattern_literals_are_left_alone() {
+    assert_eq!(describe_digit(0).as_str(), "zero");
+    assert_eq!(describe_digit(5).as_str(), "many");
+    assert_eq!(describe_digit(10).as_str(), "invalid");
+
+    let kind: SqlIdent = match (Some(-1), 'q', b'x', "users") {
+        (Some(-1), 'a'..='z', b'x', "users") => "matched",
+        _ => "unmatched",
+    };
+    assert_eq!(kind.as_str(), "matched");
+
+    let point = Point { x: 0, y: 7 };
+    let Point { x: 0, y } = point else {
+        panic!("x should be zero");
+    };
+    assert_eq!(y, 7);
+
+    let slice: &[u8] = &[1, 2, 3];
+    let rest: NonZeroU8 = if let [1, rest @ ..] = slice {
+        NonZeroU8::new(rest.len() as u8).unwrap()
+    } else {
+        0x7f
+    };
+    assert_eq!(rest.get(), 2);
+}
+
+#[test]
+#[overloaded_literals(int, char)]
+fn disabled_kinds_are_left_alone() {
+    // String and float literals stay plain, so these need no annotations
+    let message = "starting";
+    let ratio = 0.5;
+    let retries: NonZeroU8 = 3;
+    let letter: AsciiChar = 'a';
+    assert_eq!(message.len(), 8);
+    assert!(ratio < 1.0);
+    assert_eq!(retries.get(), 3);
+    assert_eq!(letter.as_byte(), b'a');
+}
+
+#[test]
+#[overloaded_literals]
+fn underscore_separated_literals() {
+    let million: NonZeroU32 = 1_000_000;
+    let plain: NonZeroU32 = 1000000;
+    assert_eq!(million, plain);
+
+    let mask: NonZeroU32 = 0xff_ff;
+    let bits: NonZeroU32 = 0b1111_1111_1111_1111;
+    assert_eq!(mask.get(), 65535);
+    assert_eq!(mask, bits);
+}
+
+/// A set of flags, of which only the lowest `BITS` bits can be set.
+#[derive(Debug, PartialEq)]
+struct Flags<const BITS: u32>(u32);
+
+impl<const BITS: u32, const LIT: u128> FromLiteralUnsigned<LIT> for Flags<BITS> {
+    const VALID_LITERAL: u128 = {
+        assert!(LIT >> BITS == 0, "Flags literal has bits set above `BITS`");
+        LIT
+    };
+
+    fn into_self() -> Self {
+        Flags(<Self as FromLiteralUnsigned<LIT>>::VALID_LITERAL as u32)
+    }
+}
+
+#[test]
+#[overloaded_literals]
+fn radix_literals() {
+    let mask: u16 = 0xFF00;
+    let mode: u32 = 0o755;
+    let nibble: u8 = 0b1010;
+    let magic: u32 = 0xdead_beef;
+    assert_eq!(mask, 65280);
+    assert_eq!(mode, 493);
+    assert_eq!(nibble, 10);
+    assert_eq!(magic, 3735928559);
+
+    let permissions: Flags<9> = 0o755;
+    let low: Flags<4> = 0b1010;
+    let high: Flags<16> = 0xFF00;
+    assert_eq!(permissions, Flags(493));
+    assert_eq!(low, Flags(10));
+    assert_eq!(high, Flags(65280));
+}
+
+#[test]
+#[overloaded_literals]
+fn const_and_static_items() {
+    const MAX: NonZeroU8 = 10;
+    const HTTPS: Port = 443;
+    static TABLE: SqlIdent = "users";
+    const ORDER: (std::cmp::Ordering, std::cmp::Ordering) = (-1, 1);
+    // Only literals whose type is known are rewritten, so these stay plain
+    const BYTES: usize = 10 * 4;
+    const RATIO: f64 = 0.5;
+    assert_eq!(MAX.get(), 10);
+    assert_eq!(HTTPS.get(), 443);
+    assert_eq!(TABLE.as_str(), "users");
+    assert_eq!(
+        ORDER,
+        (std::cmp::Ordering::Less, std::cmp::Ordering::Greater)
+    );
+    assert_eq!(BYTES, 40);
+    assert_eq!(RATIO * 2.0, 1.0);
+
+    // Outside of const items, `into_self()` is still used
+    let retries: NonZeroU8 = 3;
+    assert!(retries < MAX);
+}
+
+#[test]
+#[overloaded_literals]
+fn wrapping_literals() {
+    use std::num::Wrapping;
+
+    let counter: Wrapping<u16> = 65535;
+    let step: Wrapping<u16> = 2;
+    assert_eq!((counter + step).0, 1);
+    assert_eq!(counter.0.wrapping_add(step.0), 1);
+
+    let low: Wrapping<i8> = -128;
+    let one: Wrapping<i8> = 1;
+    assert_eq!((low - one).0, 127);
+}
+
+#[test]
+#[cfg(feature = "saturating")]
+#[overloaded_literals]
+fn saturating_literals() {
+    use std::num::Saturating;
+
+    let volume: Saturating<u8> = 250;
+    let step: Saturating<u8> = 10;
+    assert_eq!((volume + step).0, u8::MAX);
+
+    let level: Saturating<i8> = -5;
+    assert_eq!((level - Saturating(i8::MAX)).0, i8::MIN);
+}
+
+#[test]
+#[overloaded_literals]
+fn negative_nonzero_literals() {
+    use std::num::{NonZeroI16, NonZeroI64};
+
+    let n: NonZeroI16 = -30000;
+    let min: NonZeroI16 = -32768;
+    let big: NonZeroI64 = -9_000_000_000;
+    assert_eq!(n.get(), -30000);
+    assert_eq!(min.get(), i16::MIN);
+    assert_eq!(big.get(), -9_000_000_000);
+
+    // Built through `FromLiteralSigned`, so with the negative literal itself.
+    let debug: LiteralDebug<NonZeroI16> = -1;
+    assert_eq!(debug.literal(), SourceLiteral::Signed(-1));
+}
+
+#[test]
+#[overloaded_literals]
+fn positive_signed_integer_bounds() {
+    let i8_max: i8 = 127;
+    let i16_max: i16 = 32767;
+    let i32_max: i32 = 2147483647;
+    let i64_max: i64 = 9223372036854775807;
+    let i128_max: i128 = 170141183460469231731687303715884105727;
+    let small: isize = 127;
+    #[cfg(target_pointer_width = "16")]
+    let isize_max: isize = 32767;
+    #[cfg(target_pointer_width = "32")]
+    let isize_max: isize = 2147483647;
+    #[cfg(target_pointer_width = "64")]
+    let isize_max: isize = 9223372036854775807;
+    let zero: i32 = 0;
+    assert_eq!(i8_max, i8::MAX);
+    assert_eq!(i16_max, i16::MAX);
+    assert_eq!(i32_max, i32::MAX);
+    assert_eq!(i64_max, i64::MAX);
+    assert_eq!(i128_max, i128::MAX);
+    assert_eq!(small, 127);
+    assert_eq!(isize_max, isize::MAX);
+    assert_eq!(zero, 0);
+}
+
+#[test]
+#[overloaded_literals]
+fn positive_signed_nonzero_bounds() {
+    use std::num::{NonZeroI128, NonZeroI8};
+
+    // Positive literals go through `FromLiteralUnsigned`, bounded by the signed maximum.
+    let max: NonZeroI8 = 127;
+    let one: NonZeroI8 = 1;
+    let huge: NonZeroI128 = 170141183460469231731687303715884105727;
+    assert_eq!(max.get(), i8::MAX);
+    assert_eq!(one.get(), 1);
+    assert_eq!(huge.get(), i128::MAX);
+}
@@ -0,0 +1,402 @@
+//! Implementations of the derive macros for the literal traits.
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{
+    parse_quote, punctuated::Punctuated, Attribute, Data, DataEnum, DataStruct, DeriveInput, Error,
+    Expr, ExprLit, ExprRange, ExprUnary, Fields, GenericParam, Generics, Lit, LitStr, RangeLimits,
+    Token, UnOp, Variant,
+};
+
+/// Builds the generics for an impl of one of the literal traits:
+/// the generics of the type itself, with the literal's const generic parameter added.
+fn impl_generics_with(generics: &Generics, param: GenericParam) -> Generics {
+    let mut generics = generics.clone();
+    generics.params.push(param);
+    generics
+}
+
+pub fn from_literal_unsigned(input: DeriveInput) -> syn::Result<TokenStream> {
+    match &input.data {
+        Data::Enum(data) => from_literal_unsigned_enum(&input, data),
+        Data::Struct(data) => from_literal_int_newtype(&input, data, IntLiteral::Unsigned),
+        _ => Err(Error::new_spanned(
+            &input.ident,
+            "`FromLiteralUnsigned` can only be derived for enums without fields and for tuple structs with a single field",
+        )),
+    }
+}
+
+pub fn from_literal_signed(input: DeriveInput) -> syn::Result<TokenStream> {
+    match &input.data {
+        Data::Struct(data) => from_literal_int_newtype(&input, data, IntLiteral::Signed),
+        _ => Err(Error::new_spanned(
+            &input.ident,
+            "`FromLiteralSigned` can only be derived for tuple structs with a single field",
+        )),
+    }
+}
+
+/// Which of the integer literal traits is derived.
+#[derive(Clone, Copy)]
+enum IntLiteral {
+    Unsigned,
+    Signed,
+}
+
+/// The bounds given in a `#[range(LO..=HI)]` attribute, both inclusive.
+/// A missing bound means the literal is only limited by the type of the field.
+struct Range {
+    low: Option<Bound>,
+    high: Option<Bound>,
+    /// The range as written, for the error message.
+    text: String,
+}
+
+/// A bound of a `#[range(...)]` attribute,
+/// parsed as a `u128` for `FromLiteralUnsigned` and as an `i128` for `FromLiteralSigned`.
+#[derive(Clone, Copy)]
+enum Bound {
+    Unsigned(u128),
+    Signed(i128),
+    /// A bound below zero for `FromLiteralUnsigned`, which every literal is above.
+    BelowZero,
+}
+
+/// Parses an integer literal bound, which may be negative (like `-40`), into its sign and magnitude.
+fn sign_and_magnitude(expr: &Expr) -> syn::Result<(bool, u128)> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit_int),
+            ..
+        }) => Ok((false, lit_int.base10_parse()?)),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => sign_and_magnitude(expr).map(|(negative, magnitude)| (!negative, magnitude)),
+        Expr::Group(group) => sign_and_magnitude(&group.expr),
+        _ => Err(Error::new_spanned(
+            expr,
+            "expected an integer literal as bound of the range",
+        )),
+    }
+}
+
+/// Parses a bound of the range, for the literals of the given `kind`.
+fn range_bound(expr: &Expr, kind: IntLiteral) -> syn::Result<Bound> {
+    let (negative, magnitude) = sign_and_magnitude(expr)?;
+    match kind {
+        IntLiteral::Unsigned if negative && magnitude != 0 => Ok(Bound::BelowZero),
+        IntLiteral::Unsigned => Ok(Bound::Unsigned(magnitude)),
+        IntLiteral::Signed if negative && magnitude == i128::MIN.unsigned_abs() => {
+            Ok(Bound::Signed(i128::MIN))
+        }
+        IntLiteral::Signed => match i128::try_from(magnitude) {
+            Ok(bound) if negative => Ok(Bound::Signed(-bound)),
+            Ok(bound) => Ok(Bound::Signed(bound)),
+            Err(_) => Err(Error::new_spanned(
+                expr,
+                "bound of the range is out of range for `FromLiteralSigned` (i128)",
+            )),
+        },
+    }
+}
+
+/// The range given in the `#[range(...)]` attribute, if there is one.
+fn range_attribute(attrs: &[Attribute], kind: IntLiteral) -> syn::Result<Option<Range>> {
+    let Some(attr) = attrs.iter().find(|attr| attr.path().is_ident("range")) else {
+        return Ok(None);
+    };
+    let range: ExprRange = attr.parse_args()?;
+    let bound = |expr: &Expr| range_bound(expr, kind);
+    let low = range.start.as_deref().map(bound).transpose()?;
+    let high = range.end.as_deref().map(bound).transpose()?;
+    let high = match (range.limits, high) {
+        (RangeLimits::Closed(_), high) => high,
+        (RangeLimits::HalfOpen(_), None) => None,
+        (RangeLimits::HalfOpen(_), Some(Bound::Unsigned(0) | Bound::BelowZero)) => {
+            Some(Bound::BelowZero)
+        }
+        (RangeLimits::HalfOpen(_), Some(Bound::Unsigned(high))) => Some(Bound::Unsigned(high - 1)),
+        (RangeLimits::HalfOpen(_), Some(Bound::Signed(high))) => match high.checked_sub(1) {
+            Some(high) => Some(Bound::Signed(high)),
+            None => return Err(Error::new_spanned(&range, "the range is empty")),
+        },
+    };
+    let text = range.to_token_stream().to_string().replace(' ', "");
+    Ok(Some(Range { low, high, text }))
+}
+
+/// The literal is checked against the `#[range(...)]` attribute (if any),
+/// and then passed on to the implementation for the type of the field.
+fn from_literal_int_newtype(
+    input: &DeriveInput,
+    data: &DataStruct,
+    kind: IntLiteral,
+) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let (trait_name, lit_type) = match kind {
+        IntLiteral::Unsigned => (quote!(FromLiteralUnsigned), quote!(u128)),
+        IntLiteral::Signed => (quote!(FromLiteralSigned), quote!(i128)),
+    };
+    let field = match &data.fields {
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0],
+        _ => {
+            return Err(Error::new_spanned(
+                name,
+                format!("`{trait_name}` can only be derived for tuple structs with a single field"),
+            ))
+        }
+    };
+    let field_type = &field.ty;
+
+    let range = range_attribute(&input.attrs, kind)?;
+    // The conditions for `subject` (either `LIT` or the runtime `value`) to be in the range
+    let conditions = |subject: TokenStream| {
+        let mut conditions = Vec::new();
+        let Some(range) = &range else {
+            return conditions;
+        };
+        // Bounds which every literal meets are left out
+        match range.low {
+            None | Some(Bound::BelowZero | Bound::Unsigned(0) | Bound::Signed(i128::MIN)) => {}
+            Some(Bound::Unsigned(low)) => conditions.push(quote!(#subject >= #low)),
+            Some(Bound::Signed(low)) => conditions.push(quote!(#subject >= #low)),
+        }
+        match range.high {
+            None | Some(Bound::Unsigned(u128::MAX) | Bound::Signed(i128::MAX)) => {}
+            Some(Bound::BelowZero) => conditions.push(quote!(false)),
+            Some(Bound::Unsigned(high)) => conditions.push(quote!(#subject <= #high)),
+            Some(Bound::Signed(high)) => conditions.push(quote!(#subject <= #high)),
+        }
+        conditions
+    };
+    let (check, runtime_check) = match &range {
+        Some(range) if !conditions(quote!(LIT)).is_empty() => {
+            let message = LitStr::new(
+                &format!("Out of range {name} ({})", range.text),
+                name.span(),
+            );
+            let literal_conditions = conditions(quote!(LIT));
+            // One check per bound, rather than a range check spelled out by hand
+            let value_conditions = conditions(quote!(value));
+            (
+                quote!(assert!(#(#literal_conditions)&&*, #message);),
+                quote! {
+                    #(
+                        if !(#value_conditions) {
+                            return ::core::result::Result::Err(::overloaded_literals::LiteralError::OutOfRange);
+                        }
+                    )*
+                },
+            )
+        }
+        _ => (quote!(), quote!()),
+    };
+
+    let mut generics = impl_generics_with(&input.generics, parse_quote!(const LIT: #lit_type));
+    generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote!(#field_type: ::overloaded_literals::#trait_name<LIT>));
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    let mut try_generics = input.generics.clone();
+    try_generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote!(#field_type: ::overloaded_literals::TryFromLiteralValue<#lit_type>));
+    let (try_impl_generics, _, try_where_clause) = try_generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::overloaded_literals::#trait_name<LIT> for #name #ty_generics #where_clause {
+            const VALID_LITERAL: #lit_type = {
+                #check
+                <#field_type as ::overloaded_literals::#trait_name<LIT>>::VALID_LITERAL
+            };
+
+            #[inline]
+            fn into_self() -> Self {
+                let _ = <Self as ::overloaded_literals::#trait_name<LIT>>::VALID_LITERAL;
+                Self(<#field_type as ::overloaded_literals::#trait_name<LIT>>::into_self())
+            }
+        }
+
+        impl #try_impl_generics ::overloaded_literals::TryFromLiteralValue<#lit_type> for #name #ty_generics #try_where_clause {
+            #[inline]
+            fn try_from_value(value: #lit_type) -> ::core::result::Result<Self, ::overloaded_literals::LiteralError> {
+                #runtime_check
+                <#field_type as ::overloaded_literals::TryFromLiteralValue<#lit_type>>::try_from_value(value).map(Self)
+            }
+        }
+    })
+}
+
+/// Each variant is constructed from the literal equal to its discriminant.
+fn from_literal_unsigned_enum(input: &DeriveInput, data: &DataEnum) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new_spanned(
+                variant,
+                "`FromLiteralUnsigned` can only be derived for enums without fields",
+            ));
+        }
+    }
+    let variants: Vec<_> = data.variants.iter().map(|variant| &variant.ident).collect();
+    let is_valid = if variants.is_empty() {
+        quote!(false)
+    } else {
+        quote!(#(LIT == Self::#variants as u128)||*)
+    };
+    let message = LitStr::new(
+        &format!("Invalid {name} literal: not the discriminant of any of its variants"),
+        name.span(),
+    );
+
+    let generics = impl_generics_with(&input.generics, parse_quote!(const LIT: u128));
+    let (impl_generics, _, _) = generics.split_for_impl();
+    let (orig_impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::overloaded_literals::FromLiteralUnsigned<LIT> for #name #ty_generics #where_clause {
+            const VALID_LITERAL: u128 = {
+                assert!(#is_valid, #message);
+                LIT
+            };
+
+            #[inline]
+            fn into_self() -> Self {
+                let val = <Self as ::overloaded_literals::FromLiteralUnsigned<LIT>>::VALID_LITERAL;
+                #(
+                    if val == Self::#variants as u128 {
+                        return Self::#variants;
+                    }
+                )*
+                unreachable!()
+            }
+        }
+
+        impl #orig_impl_generics ::overloaded_literals::TryFromLiteralValue<u128> for #name #ty_generics #where_clause {
+            #[inline]
+            fn try_from_value(value: u128) -> ::core::result::Result<Self, ::overloaded_literals::LiteralError> {
+                #(
+                    if value == Self::#variants as u128 {
+                        return ::core::result::Result::Ok(Self::#variants);
+                    }
+                )*
+                ::core::result::Result::Err(::overloaded_literals::LiteralError::OutOfRange)
+            }
+        }
+    })
+}
+
+pub fn from_literal_str(input: DeriveInput) -> syn::Result<TokenStream> {
+    match &input.data {
+        Data::Enum(data) => from_literal_str_enum(&input, data),
+        _ => Err(Error::new_spanned(
+            &input.ident,
+            "`FromLiteralStr` can only be derived for enums without fields",
+        )),
+    }
+}
+
+/// The string literals a variant is constructed from, given by its `#[literal("...")]` attributes.
+fn variant_literals(variant: &Variant) -> syn::Result<Vec<LitStr>> {
+    let mut literals = Vec::new();
+    for attr in &variant.attrs {
+        if attr.path().is_ident("literal") {
+            let list = attr.parse_args_with(Punctuated::<LitStr, Token![,]>::parse_terminated)?;
+            literals.extend(list);
+        }
+    }
+    if literals.is_empty() {
+        return Err(Error::new_spanned(
+            &variant.ident,
+            "every variant needs a `#[literal(\"...\")]` attribute with the string literal(s) it is constructed from",
+        ));
+    }
+    Ok(literals)
+}
+
+/// Each variant is constructed from the string literals given in its `#[literal("...")]` attributes.
+fn from_literal_str_enum(input: &DeriveInput, data: &DataEnum) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let mut variants = Vec::new();
+    let mut literals: Vec<LitStr> = Vec::new();
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new_spanned(
+                variant,
+                "`FromLiteralStr` can only be derived for enums without fields",
+            ));
+        }
+        for literal in variant_literals(variant)? {
+            if literals
+                .iter()
+                .any(|other| other.value() == literal.value())
+            {
+                return Err(Error::new_spanned(
+                    &literal,
+                    "this string literal is already used for another variant",
+                ));
+            }
+            variants.push(&variant.ident);
+            literals.push(literal);
+        }
+    }
+    let is_valid = if literals.is_empty() {
+        quote!(false)
+    } else {
+        quote!(#(::overloaded_literals::type_str::const_str_eq(Str::STR, #literals))||*)
+    };
+    let expected: Vec<_> = literals
+        .iter()
+        .map(|literal| format!("{:?}", literal.value()))
+        .collect();
+    let message = LitStr::new(
+        &format!(
+            "Invalid {name} literal: expected one of {}",
+            expected.join(", ")
+        ),
+        name.span(),
+    );
+
+    let generics = impl_generics_with(
+        &input.generics,
+        parse_quote!(Str: ::overloaded_literals::TypeStr),
+    );
+    let (impl_generics, _, _) = generics.split_for_impl();
+    let (_, ty_generics, where_clause) = input.generics.split_for_impl();
+    // Any string can be checked at runtime, not only a `&'static str`
+    let value_generics = impl_generics_with(&input.generics, parse_quote!('__value));
+    let (value_impl_generics, _, _) = value_generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::overloaded_literals::FromLiteralStr<Str> for #name #ty_generics #where_clause {
+            const VALID_LITERAL: &'static str = {
+                assert!(#is_valid, #message);
+                Str::STR
+            };
+
+            #[inline]
+            fn into_self() -> Self {
+                match <Self as ::overloaded_literals::FromLiteralStr<Str>>::VALID_LITERAL {
+                    #(#literals => Self::#variants,)*
+                    _ => unreachable!(),
+                }
+            }
+        }
+
+        impl #value_impl_generics ::overloaded_literals::TryFromLiteralValue<&'__value str> for #name #ty_generics #where_clause {
+            #[inline]
+            fn try_from_value(value: &'__value str) -> ::core::result::Result<Self, ::overloaded_literals::LiteralError> {
+                match value {
+                    #(#literals => ::core::result::Result::Ok(Self::#variants),)*
+                    _ => ::core::result::Result::Err(::overloaded_literals::LiteralError::InvalidStr),
+                }
+            }
+        }
+    })
+}
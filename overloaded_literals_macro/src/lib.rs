@@ -3,13 +3,610 @@ extern crate proc_macro;
 use proc_macro::TokenStream;
 use quote::{__private::Span, quote};
 use syn::{
-    fold::Fold, parse_macro_input, parse_quote_spanned, spanned::Spanned, Expr, ExprLit, ExprUnary,
-    ItemFn, Lit, UnOp,
+    fold::Fold,
+    parse_macro_input, parse_quote_spanned,
+    punctuated::{Pair, Punctuated},
+    spanned::Spanned,
+    Arm, BinOp, Block, DeriveInput, Expr, ExprArray, ExprBinary, ExprBlock, ExprClosure, ExprIf,
+    ExprLit, ExprMacro, ExprMatch, ExprParen, ExprTuple, ExprUnary, ExprUnsafe, ImplItem, Item,
+    ItemConst, ItemFn, ItemImpl, ItemMod, ItemStatic, Lit, Macro, Pat, ReturnType, Stmt, Token,
+    Type, TypeTuple, UnOp,
 };
 
-struct Args;
+mod derive;
 
-fn wrap_signed(unsigned_expr_lit: &ExprLit, span: Span) -> Option<syn::Expr> {
+/// The arguments passed to the attribute, e.g. `#[overloaded_literals(macros, skip_macros(println))]`.
+#[derive(Default)]
+struct Args {
+    /// The kinds of literals which are rewritten, or `None` to rewrite all of them.
+    kinds: Option<Vec<LiteralKind>>,
+    /// Whether to also rewrite literals inside (the arguments of) invocations of [SUPPORTED_MACROS].
+    macros: bool,
+    /// Macros which are never descended into, even when `macros` is enabled.
+    skip_macros: Vec<syn::Ident>,
+    /// The type the expression which is about to be folded is known to have (e.g. from a `let` annotation).
+    ///
+    /// It only applies to that expression itself, so it is taken (reset to `None`) as soon as it is folded.
+    expected: Option<Type>,
+    /// Whether we are inside the condition of a `while` loop, the iterator of a `for` loop or an index like `[0]`.
+    ///
+    /// Literals there are only rewritten when marked with `#[overload]`,
+    /// since a loop bound like the `10` in `for i in 0..10` or an index like the `0` in `arr[0]`
+    /// usually has to stay a plain integer (like `usize`).
+    ///
+    /// It does not carry on into the statements of a block or the body of a closure written there,
+    /// as in `a[{ let t: NonZeroU8 = 1; t.get() as usize }]`: only the value itself is in plain position.
+    in_plain_position: bool,
+    /// Whether we are inside a statement or expression marked with `#[overloaded_literals::skip]`.
+    ///
+    /// Literals there are left alone just like in plain position (unless marked with `#[overload]`),
+    /// but this does carry on into nested blocks and closures.
+    in_skipped: bool,
+    /// Whether we are inside the initializer of a `const` or `static` item,
+    /// where `into_self()` cannot be called since it is not a `const fn`.
+    ///
+    /// Literals there are rewritten to the `VALUE` of the `ConstFromLiteral*` traits instead,
+    /// but only when their type is known and they are integer or string literals.
+    in_const_item: bool,
+    /// The return type of the function or closure whose body is being folded, if known.
+    ///
+    /// It is used for the values of `return` expressions, just like for the tail expression of the body.
+    return_type: Option<Type>,
+    /// Whether to leave the annotated item alone entirely, as with `#[overloaded_literals(skip)]`.
+    skip: bool,
+}
+
+impl Args {
+    fn parse_meta(&mut self, meta: syn::meta::ParseNestedMeta) -> syn::Result<()> {
+        if let Some((_, kinds)) = KIND_ARGS.iter().find(|(name, _)| meta.path.is_ident(name)) {
+            self.kinds.get_or_insert_with(Vec::new).extend(*kinds);
+            Ok(())
+        } else if meta.path.is_ident("macros") {
+            self.macros = true;
+            Ok(())
+        } else if meta.path.is_ident("skip") {
+            self.skip = true;
+            Ok(())
+        } else if meta.path.is_ident("skip_macros") {
+            meta.parse_nested_meta(|inner| {
+                let name = inner
+                    .path
+                    .get_ident()
+                    .ok_or_else(|| inner.error("expected a macro name"))?;
+                self.skip_macros.push(name.clone());
+                Ok(())
+            })
+        } else {
+            Err(meta.error(
+                "unsupported overloaded_literals argument; expected a literal kind (`int`, `uint`, `sint`, `str`, `bytestr`, `float`, `bool`, `char` or `byte`), `macros`, `skip_macros(...)` or `skip`",
+            ))
+        }
+    }
+
+    /// Whether literals of the given kind are rewritten.
+    fn rewrites(&self, kind: LiteralKind) -> bool {
+        match &self.kinds {
+            Some(kinds) => kinds.contains(&kind),
+            None => true,
+        }
+    }
+
+    /// Whether the literal `lit` (negated if `negative`) is rewritten.
+    ///
+    /// Literals with an explicit type suffix like `42i32` or `1.5f64` never are:
+    /// the suffix already says which type is wanted.
+    fn rewrites_lit(&self, lit: &Lit, negative: bool) -> bool {
+        let kind = match lit {
+            Lit::Int(lit_int) if !lit_int.suffix().is_empty() => return false,
+            Lit::Float(lit_float) if !lit_float.suffix().is_empty() => return false,
+            Lit::Int(_) if negative => LiteralKind::Signed,
+            Lit::Int(_) => LiteralKind::Unsigned,
+            Lit::Float(_) => LiteralKind::Float,
+            Lit::Str(_) => LiteralKind::Str,
+            Lit::ByteStr(_) => LiteralKind::ByteStr,
+            Lit::Bool(_) => LiteralKind::Bool,
+            Lit::Char(_) => LiteralKind::Char,
+            Lit::Byte(_) => LiteralKind::Byte,
+            _ => return false,
+        };
+        self.rewrites(kind)
+    }
+
+    /// Folds the functions, methods, `const`s and `static`s in an inline module (and in its inline submodules),
+    /// except for those which have their own `#[overloaded_literals]` attribute:
+    /// Those are left for their own attribute to rewrite, which then only uses its own arguments.
+    ///
+    /// Other items, like type definitions, are left alone.
+    fn fold_module(&mut self, mut item_mod: ItemMod) -> ItemMod {
+        item_mod.content = item_mod.content.map(|(brace, items)| {
+            let items = items
+                .into_iter()
+                .map(|item| match item {
+                    Item::Fn(item_fn) if !has_own_attribute(&item_fn.attrs) => {
+                        Item::Fn(self.fold_item_fn(item_fn))
+                    }
+                    Item::Impl(item_impl) if !has_own_attribute(&item_impl.attrs) => {
+                        Item::Impl(self.fold_impl_methods(item_impl))
+                    }
+                    Item::Mod(item_mod) if !has_own_attribute(&item_mod.attrs) => {
+                        Item::Mod(self.fold_module(item_mod))
+                    }
+                    Item::Const(item_const) => Item::Const(self.fold_item_const(item_const)),
+                    Item::Static(item_static) => Item::Static(self.fold_item_static(item_static)),
+                    other => other,
+                })
+                .collect();
+            (brace, items)
+        });
+        item_mod
+    }
+
+    /// Folds the methods and associated `const`s in an `impl` block,
+    /// except for methods which have their own `#[overloaded_literals]` attribute.
+    ///
+    /// Like those of `const` items, the initializers of associated `const`s use the `ConstFromLiteral*` traits.
+    fn fold_impl_methods(&mut self, mut item_impl: ItemImpl) -> ItemImpl {
+        item_impl.items = item_impl
+            .items
+            .into_iter()
+            .map(|impl_item| match impl_item {
+                ImplItem::Fn(impl_fn) if !has_own_attribute(&impl_fn.attrs) => {
+                    ImplItem::Fn(self.fold_impl_item_fn(impl_fn))
+                }
+                ImplItem::Const(impl_const) => ImplItem::Const(syn::ImplItemConst {
+                    expr: self.fold_const_initializer(impl_const.expr, &impl_const.ty),
+                    ..impl_const
+                }),
+                other => other,
+            })
+            .collect();
+        item_impl
+    }
+
+    /// Folds the condition of an `if` or a match guard.
+    ///
+    /// A `true` or `false` there is used as a plain `bool`, so it is left alone
+    /// (also when combined with `!`, `&&` and `||`), unless marked with `#[overload]`.
+    /// Other literals in the condition, like the `5` in `if x < 5`, are rewritten as usual.
+    fn fold_condition(&mut self, expr: Expr) -> Expr {
+        match expr {
+            Expr::Lit(ExprLit {
+                ref attrs,
+                lit: Lit::Bool(_),
+            }) if attrs.is_empty() => expr,
+            Expr::Unary(
+                expr_unary @ ExprUnary {
+                    op: UnOp::Not(_), ..
+                },
+            ) => Expr::Unary(ExprUnary {
+                expr: Box::new(self.fold_condition(*expr_unary.expr)),
+                ..expr_unary
+            }),
+            Expr::Binary(expr_binary) if matches!(expr_binary.op, BinOp::And(_) | BinOp::Or(_)) => {
+                Expr::Binary(ExprBinary {
+                    left: Box::new(self.fold_condition(*expr_binary.left)),
+                    right: Box::new(self.fold_condition(*expr_binary.right)),
+                    ..expr_binary
+                })
+            }
+            Expr::Paren(expr_paren) => Expr::Paren(ExprParen {
+                expr: Box::new(self.fold_condition(*expr_paren.expr)),
+                ..expr_paren
+            }),
+            other => self.fold_expr(other),
+        }
+    }
+
+    /// Folds the condition of a `while` loop, the iterator of a `for` loop or an index.
+    fn fold_plain_position(&mut self, expr: Expr) -> Expr {
+        let outer = std::mem::replace(&mut self.in_plain_position, true);
+        let res = self.fold_expr(expr);
+        self.in_plain_position = outer;
+        res
+    }
+
+    /// Runs `fold` outside of plain position, for the statements of a block or the body of a closure.
+    fn outside_plain_position<T>(&mut self, fold: impl FnOnce(&mut Self) -> T) -> T {
+        let outer = std::mem::replace(&mut self.in_plain_position, false);
+        let res = fold(self);
+        self.in_plain_position = outer;
+        res
+    }
+
+    /// Runs `fold` on something marked with `#[overloaded_literals::skip]`,
+    /// leaving literals alone (unless marked with `#[overload]`).
+    fn with_skipped_literals<T>(&mut self, fold: impl FnOnce(&mut Self) -> T) -> T {
+        let outer = std::mem::replace(&mut self.in_skipped, true);
+        let res = fold(self);
+        self.in_skipped = outer;
+        res
+    }
+
+    /// Folds the initializer `expr` of a `const` or `static` item of type `ty`.
+    fn fold_const_initializer(&mut self, expr: Expr, ty: &Type) -> Expr {
+        let outer = std::mem::replace(&mut self.in_const_item, true);
+        self.expected = known_type(ty);
+        let res = self.fold_expr(expr);
+        self.in_const_item = outer;
+        res
+    }
+
+    /// Folds the body of a function or closure with the given return type.
+    fn fold_body_returning(&mut self, body: Expr, return_type: Option<Type>) -> Expr {
+        let outer = std::mem::replace(&mut self.return_type, return_type.clone());
+        self.expected = return_type;
+        let body = self.fold_expr(body);
+        self.return_type = outer;
+        body
+    }
+
+    /// Folds the body `block` of a function with the given return type.
+    fn fold_fn_block(&mut self, block: Block, return_type: Option<Type>) -> Block {
+        let outer = std::mem::replace(&mut self.return_type, return_type.clone());
+        let block = self.fold_block_expecting(block, return_type);
+        self.return_type = outer;
+        block
+    }
+
+    /// Folds `block`, passing the `expected` type on to its tail expression (which is the value of the block).
+    fn fold_block_expecting(&mut self, mut block: Block, expected: Option<Type>) -> Block {
+        let tail = match block.stmts.last() {
+            Some(Stmt::Expr(_, None)) => block.stmts.pop(),
+            _ => None,
+        };
+        let mut block = self.fold_block(block);
+        if let Some(Stmt::Expr(tail, None)) = tail {
+            self.expected = expected;
+            block.stmts.push(Stmt::Expr(self.fold_expr(tail), None));
+        }
+        block
+    }
+
+    /// Folds `expr_if`, passing the `expected` type on to both of its branches.
+    fn fold_if_expecting(&mut self, expr_if: ExprIf, expected: Option<Type>) -> ExprIf {
+        let cond = Box::new(self.fold_condition(*expr_if.cond));
+        let then_branch = self.fold_block_expecting(expr_if.then_branch, expected.clone());
+        let else_branch = expr_if.else_branch.map(|(else_token, else_expr)| {
+            // Either a block or another `if`
+            self.expected = expected;
+            (else_token, Box::new(self.fold_expr(*else_expr)))
+        });
+        ExprIf {
+            cond,
+            then_branch,
+            else_branch,
+            ..expr_if
+        }
+    }
+
+    /// Folds `expr_match`, passing the `expected` type on to the body of each arm.
+    fn fold_match_expecting(&mut self, expr_match: ExprMatch, expected: Option<Type>) -> ExprMatch {
+        let expr = Box::new(self.fold_expr(*expr_match.expr));
+        let arms = expr_match
+            .arms
+            .into_iter()
+            .map(|arm| {
+                let pat = self.fold_pat(arm.pat);
+                let guard = arm
+                    .guard
+                    .map(|(if_token, guard)| (if_token, Box::new(self.fold_condition(*guard))));
+                self.expected = expected.clone();
+                let body = Box::new(self.fold_expr(*arm.body));
+                Arm {
+                    pat,
+                    guard,
+                    body,
+                    ..arm
+                }
+            })
+            .collect();
+        ExprMatch {
+            expr,
+            arms,
+            ..expr_match
+        }
+    }
+
+    /// Folds `expr_tuple`, passing each element type of `type_tuple` on to the corresponding element.
+    fn fold_tuple_expecting(&mut self, expr_tuple: ExprTuple, type_tuple: TypeTuple) -> ExprTuple {
+        let elems = expr_tuple
+            .elems
+            .into_pairs()
+            .zip(type_tuple.elems)
+            .map(|(pair, ty)| {
+                let (elem, comma) = pair.into_tuple();
+                self.expected = known_type(&ty);
+                Pair::new(self.fold_expr(elem), comma)
+            })
+            .collect();
+        ExprTuple {
+            elems,
+            ..expr_tuple
+        }
+    }
+
+    /// Folds `expr_array`, passing the element type `elem` of the array type on to each element.
+    fn fold_array_expecting(&mut self, expr_array: ExprArray, elem: &Type) -> ExprArray {
+        let elems = expr_array
+            .elems
+            .into_pairs()
+            .map(|pair| {
+                let (elem_expr, comma) = pair.into_tuple();
+                self.expected = known_type(elem);
+                Pair::new(self.fold_expr(elem_expr), comma)
+            })
+            .collect();
+        ExprArray {
+            elems,
+            ..expr_array
+        }
+    }
+
+    /// Folds the elements of `vec![1, 2]` or the repeated element of `vec![1; 4]` (but not the length),
+    /// passing the element type `elem` of the `Vec<T>` on to them when it is known.
+    fn fold_vec_macro(&mut self, mac: Macro, elem: Option<&Type>) -> Macro {
+        let tokens = &mac.tokens;
+        // `vec!` accepts the same syntax as an array expression
+        let Ok(array) = syn::parse2::<Expr>(quote!([#tokens])) else {
+            // Leave it to the macro itself to report a syntax error
+            return mac;
+        };
+        let tokens = match array {
+            Expr::Array(expr_array) => {
+                let elems = match elem {
+                    Some(elem) => self.fold_array_expecting(expr_array, elem),
+                    None => self.fold_expr_array(expr_array),
+                }
+                .elems;
+                quote!(#elems)
+            }
+            Expr::Repeat(repeat) => {
+                self.expected = elem.and_then(known_type);
+                let syn::ExprRepeat {
+                    expr,
+                    semi_token,
+                    len,
+                    ..
+                } = self.fold_expr_repeat(repeat);
+                quote!(#expr #semi_token #len)
+            }
+            _ => return mac,
+        };
+        Macro { tokens, ..mac }
+    }
+
+    /// Whether `mac` is a `vec!` which is not excluded with `skip_macros(vec)`.
+    fn is_vec_macro(&self, mac: &Macro) -> bool {
+        let Some(segment) = mac.path.segments.last() else {
+            return false;
+        };
+        segment.ident == "vec" && !self.skip_macros.contains(&segment.ident)
+    }
+
+    /// The shape of a macro's arguments, if the macro should be descended into.
+    fn descends_into(&self, mac: &Macro) -> Option<MacroShape> {
+        let segment = mac.path.segments.last()?;
+        if !self.macros || self.skip_macros.contains(&segment.ident) {
+            return None;
+        }
+        SUPPORTED_MACROS
+            .iter()
+            .find(|(name, _)| segment.ident == name)
+            .map(|(_, shape)| *shape)
+    }
+}
+
+/// The kinds of literals, each of which is rewritten to a call to its own trait.
+#[derive(Clone, Copy, PartialEq)]
+enum LiteralKind {
+    /// `FromLiteralUnsigned`
+    Unsigned,
+    /// `FromLiteralSigned`
+    Signed,
+    /// `FromLiteralStr`
+    Str,
+    /// `FromLiteralByteStr`
+    ByteStr,
+    /// `FromLiteralFloat`
+    Float,
+    /// `FromLiteralBool`
+    Bool,
+    /// `FromLiteralChar`
+    Char,
+    /// `FromLiteralByte`
+    Byte,
+}
+
+/// The arguments which select the kinds of literals to rewrite, like `#[overloaded_literals(int, str)]`.
+const KIND_ARGS: &[(&str, &[LiteralKind])] = &[
+    ("int", &[LiteralKind::Unsigned, LiteralKind::Signed]),
+    ("uint", &[LiteralKind::Unsigned]),
+    ("sint", &[LiteralKind::Signed]),
+    ("str", &[LiteralKind::Str]),
+    ("bytestr", &[LiteralKind::ByteStr]),
+    ("float", &[LiteralKind::Float]),
+    ("bool", &[LiteralKind::Bool]),
+    ("char", &[LiteralKind::Char]),
+    ("byte", &[LiteralKind::Byte]),
+];
+
+/// Whether `expr` is a literal, including negative number literals like `-5`.
+fn is_literal(expr: &Expr) -> bool {
+    match expr {
+        Expr::Lit(_) => true,
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => matches!(**expr, Expr::Lit(_)),
+        _ => false,
+    }
+}
+
+/// Removes the `#[overload]` marker from a literal like `#[overload] 5`,
+/// returning whether it was there.
+///
+/// The marker forces the literal to be rewritten in places where it otherwise would not be, like loop bounds and indices.
+/// It is removed in all places, since it would not compile otherwise.
+fn take_overload_marker(expr: &mut Expr) -> bool {
+    if !is_literal(expr) {
+        return false;
+    }
+    let attrs = match expr {
+        Expr::Lit(expr_lit) => &mut expr_lit.attrs,
+        Expr::Unary(expr_unary) => &mut expr_unary.attrs,
+        _ => return false,
+    };
+    let len = attrs.len();
+    attrs.retain(|attr| !attr.path().is_ident("overload"));
+    attrs.len() != len
+}
+
+/// The attributes of `expr`, for the kinds of expressions which can have them.
+fn expr_attrs_mut(expr: &mut Expr) -> Option<&mut Vec<syn::Attribute>> {
+    macro_rules! attrs_of {
+        ($($variant:ident),*) => {
+            match expr {
+                $(Expr::$variant(inner) => Some(&mut inner.attrs),)*
+                _ => None,
+            }
+        };
+    }
+    attrs_of!(
+        Array, Assign, Async, Await, Binary, Block, Break, Call, Cast, Closure, Const, Continue,
+        Field, ForLoop, Group, If, Index, Infer, Let, Lit, Loop, Macro, Match, MethodCall, Paren,
+        Path, Range, Reference, Repeat, Return, Struct, Try, TryBlock, Tuple, Unary, Unsafe, While,
+        Yield
+    )
+}
+
+/// The attributes of `item`, for the kinds of items which can have them.
+fn item_attrs_mut(item: &mut Item) -> Option<&mut Vec<syn::Attribute>> {
+    macro_rules! attrs_of {
+        ($($variant:ident),*) => {
+            match item {
+                $(Item::$variant(inner) => Some(&mut inner.attrs),)*
+                _ => None,
+            }
+        };
+    }
+    attrs_of!(
+        Const,
+        Enum,
+        ExternCrate,
+        Fn,
+        ForeignMod,
+        Impl,
+        Macro,
+        Mod,
+        Static,
+        Struct,
+        Trait,
+        TraitAlias,
+        Type,
+        Union,
+        Use
+    )
+}
+
+/// Removes the `#[overloaded_literals::skip]` marker from the attributes of a statement, expression or item,
+/// returning whether it was there.
+///
+/// Literals inside a statement or expression marked like this are left alone, unless marked with `#[overload]`.
+fn take_skip_marker(attrs: Option<&mut Vec<syn::Attribute>>) -> bool {
+    let Some(attrs) = attrs else {
+        return false;
+    };
+    let len = attrs.len();
+    attrs.retain(|attr| {
+        let segments = &attr.path().segments;
+        !(segments.len() == 2
+            && segments[0].ident == "overloaded_literals"
+            && segments[1].ident == "skip")
+    });
+    attrs.len() != len
+}
+
+/// Whether an item has its own `#[overloaded_literals]` attribute,
+/// which takes precedence over the one on the surrounding module.
+fn has_own_attribute(attrs: &[syn::Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        matches!(attr.path().segments.last(), Some(segment) if segment.ident == "overloaded_literals")
+    })
+}
+
+/// How the (comma-separated) arguments of a supported macro are laid out.
+#[derive(Clone, Copy)]
+enum MacroShape {
+    /// Every argument is an expression, like `dbg!(a, b)`.
+    Exprs,
+    /// A format string followed by the arguments, like `format!("{} {}", a, b)`.
+    Format,
+    /// A destination, a format string and the arguments, like `write!(f, "{} {}", a, b)`.
+    Write,
+    /// The elements of an array expression, like `vec![a, b]` or `vec![a; n]`.
+    Vec,
+}
+
+impl MacroShape {
+    /// The position of the format string, which should never be rewritten.
+    fn format_string_index(self) -> Option<usize> {
+        match self {
+            MacroShape::Exprs | MacroShape::Vec => None,
+            MacroShape::Format => Some(0),
+            MacroShape::Write => Some(1),
+        }
+    }
+}
+
+/// Macros whose arguments are a list of expressions (of a known [MacroShape]),
+/// and which are therefore safe to descend into when `macros` is enabled.
+///
+/// Arbitrary macros can contain any tokens, so they are never descended into.
+const SUPPORTED_MACROS: &[(&str, MacroShape)] = &[
+    ("dbg", MacroShape::Exprs),
+    ("format", MacroShape::Format),
+    ("format_args", MacroShape::Format),
+    ("print", MacroShape::Format),
+    ("println", MacroShape::Format),
+    ("eprint", MacroShape::Format),
+    ("eprintln", MacroShape::Format),
+    ("write", MacroShape::Write),
+    ("writeln", MacroShape::Write),
+    ("vec", MacroShape::Vec),
+];
+
+/// Builds a call to `into_self` of the literal trait `trait_name` (like `FromLiteralUnsigned`), instantiated with `arg`.
+///
+/// When the type of the literal is known, the call names it explicitly (`<T as FromLiteralUnsigned<5>>::into_self()`),
+/// so the right implementation is picked without relying on inference,
+/// and a type which does not implement the trait is reported as such.
+fn into_self_call(
+    trait_name: proc_macro2::TokenStream,
+    arg: proc_macro2::TokenStream,
+    expected: Option<&Type>,
+    span: Span,
+) -> syn::Expr {
+    match expected {
+        Some(ty) => {
+            parse_quote_spanned!(span=> <#ty as ::overloaded_literals::#trait_name<#arg>>::into_self())
+        }
+        None => {
+            parse_quote_spanned!(span=> ::overloaded_literals::#trait_name::<#arg>::into_self())
+        }
+    }
+}
+
+fn float_type(float: f64, span: Span) -> proc_macro2::TokenStream {
+    let float_bits: u64 = float.to_bits();
+    quote::quote_spanned!(span=> ::overloaded_literals::type_float::Float<#float_bits>)
+}
+
+fn wrap_signed(
+    unsigned_expr_lit: &ExprLit,
+    expected: Option<&Type>,
+    span: Span,
+) -> Option<syn::Expr> {
     match unsigned_expr_lit {
         ExprLit {
             attrs,
@@ -18,7 +615,7 @@ fn wrap_signed(unsigned_expr_lit: &ExprLit, span: Span) -> Option<syn::Expr> {
             if !attrs.is_empty() {
                 return None;
             }
-            let res = parse_quote_spanned!(span=> ::overloaded_literals::FromLiteralSigned::<-#lit_int>::into_self() );
+            let res = into_self_call(quote!(FromLiteralSigned), quote!(-#lit_int), expected, span);
             Some(res)
         }
         ExprLit {
@@ -29,8 +626,12 @@ fn wrap_signed(unsigned_expr_lit: &ExprLit, span: Span) -> Option<syn::Expr> {
                 return None;
             }
             let float = lit_float.base10_parse::<f64>().unwrap();
-            let float_bits: u64 = (-float).to_bits();
-            let res = parse_quote_spanned!(span=> ::overloaded_literals::FromLiteralFloat::<::overloaded_literals::type_float::Float<#float_bits>>::into_self());
+            let res = into_self_call(
+                quote!(FromLiteralFloat),
+                float_type(-float, span),
+                expected,
+                span,
+            );
             Some(res)
         }
 
@@ -41,7 +642,7 @@ fn wrap_signed(unsigned_expr_lit: &ExprLit, span: Span) -> Option<syn::Expr> {
 // NOTE: Make sure this value is not larger than the one in `overloaded_literals_macro`
 const MAX_STR_LIT_LEN: usize = 32768;
 
-fn wrap_unsigned_or_str(expr_lit: ExprLit, span: Span) -> syn::Expr {
+fn wrap_unsigned_or_str(expr_lit: ExprLit, expected: Option<&Type>, span: Span) -> syn::Expr {
     match &expr_lit {
         ExprLit {
             attrs,
@@ -50,8 +651,12 @@ fn wrap_unsigned_or_str(expr_lit: ExprLit, span: Span) -> syn::Expr {
             if !attrs.is_empty() {
                 return Expr::Lit(expr_lit);
             }
-            let res = parse_quote_spanned!(span=> ::overloaded_literals::FromLiteralUnsigned::<#expr_lit>::into_self());
-            res
+            into_self_call(
+                quote!(FromLiteralUnsigned),
+                quote!(#expr_lit),
+                expected,
+                span,
+            )
         }
         ExprLit {
             attrs,
@@ -63,7 +668,29 @@ fn wrap_unsigned_or_str(expr_lit: ExprLit, span: Span) -> syn::Expr {
             if lit_str.value().len() > MAX_STR_LIT_LEN {
                 return Expr::Lit(expr_lit);
             }
-            build_typestr(&lit_str.value(), span)
+            build_typestr(
+                quote!(FromLiteralStr),
+                lit_str.value().as_bytes(),
+                expected,
+                span,
+            )
+        }
+        ExprLit {
+            attrs,
+            lit: Lit::ByteStr(lit_byte_str),
+        } => {
+            if !attrs.is_empty() {
+                return Expr::Lit(expr_lit);
+            }
+            if lit_byte_str.value().len() > MAX_STR_LIT_LEN {
+                return Expr::Lit(expr_lit);
+            }
+            build_typestr(
+                quote!(FromLiteralByteStr),
+                &lit_byte_str.value(),
+                expected,
+                span,
+            )
         }
         ExprLit {
             attrs,
@@ -72,8 +699,25 @@ fn wrap_unsigned_or_str(expr_lit: ExprLit, span: Span) -> syn::Expr {
             if !attrs.is_empty() {
                 return Expr::Lit(expr_lit);
             }
-            let res = parse_quote_spanned!(span=> ::overloaded_literals::FromLiteralBool::<#expr_lit>::into_self());
-            res
+            into_self_call(quote!(FromLiteralBool), quote!(#expr_lit), expected, span)
+        }
+        ExprLit {
+            attrs,
+            lit: Lit::Char(_),
+        } => {
+            if !attrs.is_empty() {
+                return Expr::Lit(expr_lit);
+            }
+            into_self_call(quote!(FromLiteralChar), quote!(#expr_lit), expected, span)
+        }
+        ExprLit {
+            attrs,
+            lit: Lit::Byte(_),
+        } => {
+            if !attrs.is_empty() {
+                return Expr::Lit(expr_lit);
+            }
+            into_self_call(quote!(FromLiteralByte), quote!(#expr_lit), expected, span)
         }
         ExprLit {
             attrs,
@@ -82,31 +726,145 @@ fn wrap_unsigned_or_str(expr_lit: ExprLit, span: Span) -> syn::Expr {
             if !attrs.is_empty() {
                 return Expr::Lit(expr_lit);
             }
-            let float_bits: u64 = lit_float.base10_parse::<f64>().unwrap().to_bits();
-            let res = parse_quote_spanned!(span=> ::overloaded_literals::FromLiteralFloat::<::overloaded_literals::type_float::Float<#float_bits>>::into_self());
-            res
+            let float = lit_float.base10_parse::<f64>().unwrap();
+            into_self_call(
+                quote!(FromLiteralFloat),
+                float_type(float, span),
+                expected,
+                span,
+            )
         }
         other => Expr::Lit(other.clone()),
     }
 }
 
-fn build_typestr(string: &str, span: Span) -> syn::Expr {
+/// Builds a call to `into_self` of `trait_name`, instantiated with the type-level list of `Byte`s of `bytes`.
+/// Used for both string and byte string literals.
+fn build_typestr(
+    trait_name: proc_macro2::TokenStream,
+    bytes: &[u8],
+    expected: Option<&Type>,
+    span: Span,
+) -> syn::Expr {
+    into_self_call(trait_name, typestr(bytes, span), expected, span)
+}
+
+/// The type-level list of `Byte`s of `bytes`.
+fn typestr(bytes: &[u8], span: Span) -> proc_macro2::TokenStream {
     let mut res = quote!(::tlist::TNil);
-    for byte in string.as_bytes().iter().rev() {
-        res = parse_quote_spanned!(span=> ::tlist::TCons<::overloaded_literals::type_str::Byte<#byte>, #res>);
+    for byte in bytes.iter().rev() {
+        res = quote::quote_spanned!(span=> ::tlist::TCons<::overloaded_literals::type_str::Byte<#byte>, #res>);
     }
-    let res =
-        parse_quote_spanned!(span=> ::overloaded_literals::FromLiteralStr::<#res>::into_self());
     res
 }
 
+/// Builds the `VALUE` of the `ConstFromLiteral*` trait for the literal `expr` of type `ty`,
+/// for use in the initializer of a `const` or `static` item.
+///
+/// Only integer and string literals have such a trait, so `None` is returned for others.
+fn const_value(expr: &Expr, ty: &Type, span: Span) -> Option<syn::Expr> {
+    let (trait_name, arg) = match expr {
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => match &**expr {
+            Expr::Lit(ExprLit {
+                attrs,
+                lit: Lit::Int(lit_int),
+            }) if attrs.is_empty() => (quote!(ConstFromLiteralSigned), quote!(-#lit_int)),
+            _ => return None,
+        },
+        Expr::Lit(ExprLit {
+            attrs,
+            lit: Lit::Int(lit_int),
+        }) if attrs.is_empty() => (quote!(ConstFromLiteralUnsigned), quote!(#lit_int)),
+        Expr::Lit(ExprLit {
+            attrs,
+            lit: Lit::Str(lit_str),
+        }) if attrs.is_empty() && lit_str.value().len() <= MAX_STR_LIT_LEN => (
+            quote!(ConstFromLiteralStr),
+            typestr(lit_str.value().as_bytes(), span),
+        ),
+        _ => return None,
+    };
+    Some(parse_quote_spanned!(span=> <#ty as ::overloaded_literals::#trait_name<#arg>>::VALUE))
+}
+
+/// The return type of a function, unless it has to be inferred (like `-> impl Iterator<Item = u8>`).
+fn fn_return_type(output: &ReturnType) -> Option<Type> {
+    match output {
+        ReturnType::Type(_, ty) if !mentions_impl_trait(quote!(#ty)) => known_type(ty),
+        _ => None,
+    }
+}
+
+/// Whether `tokens` (of a type) contain `impl`, as in `impl Trait` or `Box<impl Trait>`.
+fn mentions_impl_trait(tokens: proc_macro2::TokenStream) -> bool {
+    tokens.into_iter().any(|tree| match tree {
+        proc_macro2::TokenTree::Ident(ident) => ident == "impl",
+        proc_macro2::TokenTree::Group(group) => mentions_impl_trait(group.stream()),
+        _ => false,
+    })
+}
+
+/// The given type, unless it has to be inferred (`_`).
+fn known_type(ty: &Type) -> Option<Type> {
+    match ty {
+        Type::Infer(_) => None,
+        Type::Paren(paren) => known_type(&paren.elem),
+        ty => Some(ty.clone()),
+    }
+}
+
+/// The element type `T` of a `Vec<T>` (also written as `std::vec::Vec<T>` and the like), if it is known.
+fn vec_element_type(ty: &Type) -> Option<Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Vec" {
+        return None;
+    }
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        syn::GenericArgument::Type(elem) => known_type(elem),
+        _ => None,
+    }
+}
+
 impl Fold for Args {
     // We fold at the level of `Expr` because when we change a literal, the result will be an `Expr`.
-    fn fold_expr(&mut self, expr: syn::Expr) -> syn::Expr {
+    fn fold_expr(&mut self, mut expr: syn::Expr) -> syn::Expr {
         // Needed since we want to traverse bottom-up and leave all other nodes intact:
         // let expr = syn::fold::fold_expr(self, expr);
 
+        if take_skip_marker(expr_attrs_mut(&mut expr)) {
+            return self.with_skipped_literals(|args| args.fold_expr(expr));
+        }
         let span = expr.span();
+        let expected = self.expected.take();
+        let marked = take_overload_marker(&mut expr);
+        if (self.in_plain_position || self.in_skipped) && !marked && is_literal(&expr) {
+            return expr;
+        }
+        if self.in_const_item && is_literal(&expr) {
+            let rewritten = match (&expr, &expected) {
+                (Expr::Lit(expr_lit), Some(ty)) if self.rewrites_lit(&expr_lit.lit, false) => {
+                    const_value(&expr, ty, span)
+                }
+                (Expr::Unary(ExprUnary { expr: lit, .. }), Some(ty)) => match &**lit {
+                    Expr::Lit(expr_lit) if self.rewrites_lit(&expr_lit.lit, true) => {
+                        const_value(&expr, ty, span)
+                    }
+                    _ => None,
+                },
+                _ => None,
+            };
+            return rewritten.unwrap_or(expr);
+        }
         match expr {
             // Negative int literals are represented as Expr::Unary(UnOp::Neg, Expr::Lit(...))
             Expr::Unary(ExprUnary {
@@ -114,35 +872,369 @@ impl Fold for Args {
                 op: op @ UnOp::Neg(_),
                 expr: boxed_expr,
             }) => match &*boxed_expr {
-                Expr::Lit(expr_lit) => wrap_signed(expr_lit, span).unwrap_or_else(|| {
+                Expr::Lit(expr_lit) if !self.rewrites_lit(&expr_lit.lit, true) => {
                     Expr::Unary(ExprUnary {
                         attrs,
                         op,
                         expr: boxed_expr,
                     })
-                }),
+                }
+                Expr::Lit(expr_lit) => wrap_signed(expr_lit, expected.as_ref(), span)
+                    .unwrap_or_else(|| {
+                        Expr::Unary(ExprUnary {
+                            attrs,
+                            op,
+                            expr: boxed_expr,
+                        })
+                    }),
                 _ => {
                     let expr = Box::new(self.fold_expr(*boxed_expr));
                     Expr::Unary(ExprUnary { attrs, op, expr })
                 }
             },
+            Expr::Lit(expr_lit) if !self.rewrites_lit(&expr_lit.lit, false) => Expr::Lit(expr_lit),
             Expr::Lit(expr_lit) => {
                 // Positive int or string literals are 'plain' Expr::Lit
-                wrap_unsigned_or_str(expr_lit, span)
+                wrap_unsigned_or_str(expr_lit, expected.as_ref(), span)
+            }
+            // Parentheses do not change the type
+            Expr::Paren(_) | Expr::Group(_) => {
+                self.expected = expected;
+                syn::fold::fold_expr(self, expr)
+            }
+            // The value of a block, `if` or `match` is that of its tail expression(s)
+            Expr::Block(expr_block) if expected.is_some() => Expr::Block(ExprBlock {
+                block: self.fold_block_expecting(expr_block.block, expected),
+                ..expr_block
+            }),
+            Expr::Unsafe(expr_unsafe) if expected.is_some() => Expr::Unsafe(ExprUnsafe {
+                block: self.fold_block_expecting(expr_unsafe.block, expected),
+                ..expr_unsafe
+            }),
+            Expr::If(expr_if) if expected.is_some() => {
+                Expr::If(self.fold_if_expecting(expr_if, expected))
+            }
+            Expr::Match(expr_match) if expected.is_some() => {
+                Expr::Match(self.fold_match_expecting(expr_match, expected))
             }
+            // The value of `return` has the return type of the surrounding function or closure
+            Expr::Return(expr_return) => {
+                self.expected = self.return_type.clone();
+                syn::fold::fold_expr(self, Expr::Return(expr_return))
+            }
+            // Each element of a tuple has the type at the same position in the tuple type
+            Expr::Tuple(expr_tuple) => match expected {
+                Some(Type::Tuple(type_tuple))
+                    if type_tuple.elems.len() == expr_tuple.elems.len() =>
+                {
+                    Expr::Tuple(self.fold_tuple_expecting(expr_tuple, type_tuple))
+                }
+                // On a mismatch, the compiler will report a type error anyway
+                _ => syn::fold::fold_expr(self, Expr::Tuple(expr_tuple)),
+            },
+            // Each element of an array has the element type of the array type
+            Expr::Array(expr_array) => match expected {
+                Some(Type::Array(type_array)) => {
+                    Expr::Array(self.fold_array_expecting(expr_array, &type_array.elem))
+                }
+                _ => syn::fold::fold_expr(self, Expr::Array(expr_array)),
+            },
+            // As does the repeated element of `[1; 4]` (but not the length)
+            Expr::Repeat(repeat) => {
+                if let Some(Type::Array(type_array)) = expected {
+                    self.expected = known_type(&type_array.elem);
+                }
+                Expr::Repeat(self.fold_expr_repeat(repeat))
+            }
+            // And so do those of `vec![1, 2, 3]` or `vec![1; 4]` for `Vec<T>`, even without `macros`
+            Expr::Macro(expr_macro) => match expected.as_ref().and_then(vec_element_type) {
+                Some(elem) if self.is_vec_macro(&expr_macro.mac) => Expr::Macro(ExprMacro {
+                    mac: self.fold_vec_macro(expr_macro.mac, Some(&elem)),
+                    ..expr_macro
+                }),
+                _ => syn::fold::fold_expr(self, Expr::Macro(expr_macro)),
+            },
             other => syn::fold::fold_expr(self, other),
         }
     }
+
+    fn fold_macro(&mut self, mac: Macro) -> Macro {
+        let Some(shape) = self.descends_into(&mac) else {
+            return mac;
+        };
+        if let MacroShape::Vec = shape {
+            return self.fold_vec_macro(mac, None);
+        }
+        let Ok(args) = mac.parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated) else {
+            // Leave it to the macro itself to report a syntax error
+            return mac;
+        };
+        let args: Punctuated<Expr, Token![,]> = args
+            .into_iter()
+            .enumerate()
+            .map(|(index, arg)| {
+                if Some(index) == shape.format_string_index() {
+                    arg
+                } else {
+                    self.fold_expr(arg)
+                }
+            })
+            .collect();
+        Macro {
+            tokens: quote!(#args),
+            ..mac
+        }
+    }
+
+    // Only the tail expression of a block is the value of the block,
+    // so a block in plain position (like the index in `a[{ let t: NonZeroU8 = 1; t.get() as usize }]`)
+    // does not make its other statements plain.
+    fn fold_stmt(&mut self, mut stmt: Stmt) -> Stmt {
+        if let Stmt::Macro(stmt_macro) = &mut stmt {
+            if take_skip_marker(Some(&mut stmt_macro.attrs)) {
+                return self.with_skipped_literals(|args| syn::fold::fold_stmt(args, stmt));
+            }
+        }
+        if let Stmt::Expr(_, None) = stmt {
+            return syn::fold::fold_stmt(self, stmt);
+        }
+        self.outside_plain_position(|args| syn::fold::fold_stmt(args, stmt))
+    }
+
+    // A nested item (like a function) marked with `#[overloaded_literals::skip]` is left alone as a whole.
+    fn fold_item(&mut self, mut item: Item) -> Item {
+        if take_skip_marker(item_attrs_mut(&mut item)) {
+            return self.with_skipped_literals(|args| syn::fold::fold_item(args, item));
+        }
+        syn::fold::fold_item(self, item)
+    }
+
+    // A type annotation like `let x: NonZeroU8 = 5;` tells us the type of the initializer.
+    fn fold_local(&mut self, mut local: syn::Local) -> syn::Local {
+        if take_skip_marker(Some(&mut local.attrs)) {
+            return self.with_skipped_literals(|args| args.fold_local(local));
+        }
+        let init = local.init.take();
+        let expected = match &local.pat {
+            Pat::Type(pat_type) => known_type(&pat_type.ty),
+            _ => None,
+        };
+        let mut local = syn::fold::fold_local(self, local);
+        local.init = init.map(|init| {
+            // Only the initializer itself; the `else` block of a let-else diverges.
+            self.expected = expected;
+            syn::fold::fold_local_init(self, init)
+        });
+        local
+    }
+
+    // Nested functions, `impl` blocks and modules are rewritten too,
+    // except for those with an `#[overloaded_literals]` attribute of their own: that attribute rewrites them instead.
+    //
+    // The return type of a function is used for its tail expression and the values of its `return` expressions.
+    fn fold_item_fn(&mut self, item_fn: ItemFn) -> ItemFn {
+        if has_own_attribute(&item_fn.attrs) {
+            return item_fn;
+        }
+        let return_type = fn_return_type(&item_fn.sig.output);
+        ItemFn {
+            block: Box::new(self.fold_fn_block(*item_fn.block, return_type)),
+            ..item_fn
+        }
+    }
+
+    fn fold_item_impl(&mut self, item_impl: ItemImpl) -> ItemImpl {
+        if has_own_attribute(&item_impl.attrs) {
+            return item_impl;
+        }
+        self.fold_impl_methods(item_impl)
+    }
+
+    fn fold_impl_item_fn(&mut self, impl_fn: syn::ImplItemFn) -> syn::ImplItemFn {
+        if has_own_attribute(&impl_fn.attrs) {
+            return impl_fn;
+        }
+        let return_type = fn_return_type(&impl_fn.sig.output);
+        syn::ImplItemFn {
+            block: self.fold_fn_block(impl_fn.block, return_type),
+            ..impl_fn
+        }
+    }
+
+    // `into_self()` cannot be called in `const` and `static` items, so those use the `ConstFromLiteral*` traits instead.
+    fn fold_item_const(&mut self, item_const: ItemConst) -> ItemConst {
+        ItemConst {
+            expr: Box::new(self.fold_const_initializer(*item_const.expr, &item_const.ty)),
+            ..item_const
+        }
+    }
+
+    fn fold_item_static(&mut self, item_static: ItemStatic) -> ItemStatic {
+        ItemStatic {
+            expr: Box::new(self.fold_const_initializer(*item_static.expr, &item_static.ty)),
+            ..item_static
+        }
+    }
+
+    fn fold_item_mod(&mut self, item_mod: ItemMod) -> ItemMod {
+        if has_own_attribute(&item_mod.attrs) {
+            return item_mod;
+        }
+        syn::fold::fold_item_mod(self, item_mod)
+    }
+
+    // A return type annotation like `|x: u8| -> NonZeroU8 { 5 }` tells us the type of the closure's body.
+    fn fold_expr_closure(&mut self, closure: ExprClosure) -> ExprClosure {
+        let expected = match &closure.output {
+            ReturnType::Type(_, ty) => known_type(ty),
+            ReturnType::Default => None,
+        };
+        let inputs = closure
+            .inputs
+            .into_pairs()
+            .map(|pair| {
+                let (pat, comma) = pair.into_tuple();
+                Pair::new(self.fold_pat(pat), comma)
+            })
+            .collect();
+        let body = Box::new(
+            self.outside_plain_position(|args| args.fold_body_returning(*closure.body, expected)),
+        );
+        ExprClosure {
+            inputs,
+            body,
+            ..closure
+        }
+    }
+
+    // A `return` inside of an `async` block returns from the block rather than from the function.
+    fn fold_expr_async(&mut self, expr_async: syn::ExprAsync) -> syn::ExprAsync {
+        let outer = self.return_type.take();
+        let expr_async = syn::fold::fold_expr_async(self, expr_async);
+        self.return_type = outer;
+        expr_async
+    }
+
+    fn fold_expr_if(&mut self, expr_if: ExprIf) -> ExprIf {
+        self.fold_if_expecting(expr_if, None)
+    }
+
+    fn fold_arm(&mut self, arm: Arm) -> Arm {
+        Arm {
+            pat: self.fold_pat(arm.pat),
+            guard: arm
+                .guard
+                .map(|(if_token, guard)| (if_token, Box::new(self.fold_condition(*guard)))),
+            body: Box::new(self.fold_expr(*arm.body)),
+            ..arm
+        }
+    }
+
+    fn fold_expr_while(&mut self, expr_while: syn::ExprWhile) -> syn::ExprWhile {
+        syn::ExprWhile {
+            cond: Box::new(self.fold_plain_position(*expr_while.cond)),
+            body: self.fold_block(expr_while.body),
+            ..expr_while
+        }
+    }
+
+    fn fold_expr_for_loop(&mut self, expr_for_loop: syn::ExprForLoop) -> syn::ExprForLoop {
+        syn::ExprForLoop {
+            pat: Box::new(self.fold_pat(*expr_for_loop.pat)),
+            expr: Box::new(self.fold_plain_position(*expr_for_loop.expr)),
+            body: self.fold_block(expr_for_loop.body),
+            ..expr_for_loop
+        }
+    }
+
+    // Only the indexed expression is rewritten, as in `&[1, 2, 3][..]`; literals in the index (like `arr[0]`) are left alone.
+    fn fold_expr_index(&mut self, expr_index: syn::ExprIndex) -> syn::ExprIndex {
+        syn::ExprIndex {
+            expr: Box::new(self.fold_expr(*expr_index.expr)),
+            index: Box::new(self.fold_plain_position(*expr_index.index)),
+            ..expr_index
+        }
+    }
+
+    // Literals in patterns (like the `0` in `Some(0)`, or both ends of `1..=9`) have to stay literals, so are never rewritten.
+    fn fold_pat(&mut self, pat: Pat) -> Pat {
+        pat
+    }
+
+    // Literals inside attributes (like `#[doc = "..."]` on nested items) are never rewritten.
+    fn fold_attribute(&mut self, attr: syn::Attribute) -> syn::Attribute {
+        attr
+    }
+
+    // The length of an array repeat expression like `[0; 10]` has to be a constant, so it is never rewritten.
+    fn fold_expr_repeat(&mut self, repeat: syn::ExprRepeat) -> syn::ExprRepeat {
+        syn::ExprRepeat {
+            expr: Box::new(self.fold_expr(*repeat.expr)),
+            ..repeat
+        }
+    }
+
+    // Literals in type position (like the `4` in `[u8; 4]` or `SizeAlign<8>`) are never rewritten.
+    fn fold_type(&mut self, ty: syn::Type) -> syn::Type {
+        ty
+    }
+
+    // Neither are const generic arguments in turbofishes, like `size_of::<[u8; 4]>()`.
+    fn fold_generic_argument(&mut self, arg: syn::GenericArgument) -> syn::GenericArgument {
+        arg
+    }
 }
 
 #[proc_macro_attribute]
-pub fn overloaded_literals(_metadata: TokenStream, input: TokenStream) -> TokenStream {
-    let input_fn = parse_macro_input!(input as ItemFn);
-    let mut args = Args;
-    let output = args.fold_item_fn(input_fn);
+pub fn overloaded_literals(metadata: TokenStream, input: TokenStream) -> TokenStream {
+    let mut args = Args::default();
+    if !metadata.is_empty() {
+        let args_parser = syn::meta::parser(|meta| args.parse_meta(meta));
+        parse_macro_input!(metadata with args_parser);
+    }
+    if args.skip {
+        return input;
+    }
+    let output = match parse_macro_input!(input as Item) {
+        Item::Fn(item_fn) => Item::Fn(args.fold_item_fn(item_fn)),
+        Item::Impl(item_impl) => Item::Impl(args.fold_impl_methods(item_impl)),
+        Item::Mod(item_mod) if item_mod.content.is_some() => Item::Mod(args.fold_module(item_mod)),
+        other => {
+            return syn::Error::new_spanned(
+                other,
+                "#[overloaded_literals] can only be used on functions, `impl` blocks and inline modules",
+            )
+            .into_compile_error()
+            .into()
+        }
+    };
     TokenStream::from(quote!(#output))
 }
 
+#[proc_macro_derive(FromLiteralUnsigned, attributes(range))]
+pub fn derive_from_literal_unsigned(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive::from_literal_unsigned(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+#[proc_macro_derive(FromLiteralSigned, attributes(range))]
+pub fn derive_from_literal_signed(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive::from_literal_signed(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+#[proc_macro_derive(FromLiteralStr, attributes(literal))]
+pub fn derive_from_literal_str(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive::from_literal_str(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
 // These tests are mainly here for debugging;
 // They (only) ensure the happy path does not crash.
 // (And if it does, we have relatively easy debugging)
@@ -162,7 +1254,7 @@ mod tests {
                 res
             }
         };
-        let mut args = Args;
+        let mut args = Args::default();
         let _out = args.fold_item_fn(input_fun);
         // println!("{:?}", out)
     }
@@ -175,7 +1267,7 @@ mod tests {
                 res
             }
         };
-        let mut args = Args;
+        let mut args = Args::default();
         let _out = args.fold_item_fn(input_fun);
         // println!("{:?}", out)
     }
@@ -188,7 +1280,7 @@ mod tests {
                 res
             }
         };
-        let mut args = Args;
+        let mut args = Args::default();
         let _out = args.fold_item_fn(input_fun);
         // println!("{:?}", out)
     }
@@ -201,7 +1293,7 @@ mod tests {
     //             res
     //         }
     //     };
-    //     let mut args = Args;
+    //     let mut args = Args::default();
     //     let _out = args.fold_item_fn(input_fun);
     //     // println!("{:?}", out)
     // }
@@ -216,8 +1308,775 @@ mod tests {
                 let four : i8 = -33;
             }
         };
-        let mut args = Args;
+        let mut args = Args::default();
         let _out = args.fold_item_fn(input_fun);
         // println!("{:?}", out)
     }
+
+    #[test]
+    fn char_example() {
+        let input_fun: ItemFn = parse_quote! {
+            fn foo() {
+                let a: AsciiChar = 'a';
+                let lambda = 'λ';
+                let byte: PrintableByte = b'A';
+            }
+        };
+        let mut args = Args::default();
+        let out = args.fold_item_fn(input_fun);
+        let out = quote!(#out).to_string();
+        assert!(out.contains(
+            "< AsciiChar as :: overloaded_literals :: FromLiteralChar < 'a' > > :: into_self ()"
+        ));
+        assert!(
+            out.contains(":: overloaded_literals :: FromLiteralChar :: < 'λ' > :: into_self ()")
+        );
+        assert!(out.contains(
+            "< PrintableByte as :: overloaded_literals :: FromLiteralByte < b'A' > > :: into_self ()"
+        ));
+    }
+
+    #[test]
+    fn byte_str_example() {
+        let input_fun: ItemFn = parse_quote! {
+            fn foo() {
+                let mac: MacAddress = b"\x00\xFF";
+            }
+        };
+        let mut args = Args::default();
+        let out = args.fold_item_fn(input_fun);
+        let out = quote!(#out).to_string();
+        assert!(out.contains(
+            "< MacAddress as :: overloaded_literals :: FromLiteralByteStr < :: tlist :: TCons < :: overloaded_literals :: type_str :: Byte < 0u8 > , :: tlist :: TCons < :: overloaded_literals :: type_str :: Byte < 255u8 > , :: tlist :: TNil > > > > :: into_self ()"
+        ));
+    }
+
+    #[test]
+    fn macros_example() {
+        let input_fun = parse_quote! {
+            fn foo() {
+                let one: u8 = dbg!(1);
+                let two: u8 = std::dbg!(2, "hello",);
+                println!("{} {name}", 3, name = "x");
+                writeln!(f, "{}", 4);
+            }
+        };
+        let mut args = Args {
+            macros: true,
+            ..Args::default()
+        };
+        let _out = args.fold_item_fn(input_fun);
+        // println!("{:?}", out)
+    }
+
+    #[test]
+    fn skip_macros_example() {
+        let input_fun: ItemFn = parse_quote! {
+            fn foo() {
+                let one: u8 = dbg!(1);
+            }
+        };
+        let mut args = Args::default();
+        let args_parser = syn::meta::parser(|meta| args.parse_meta(meta));
+        syn::parse::Parser::parse2(args_parser, quote!(macros, skip_macros(dbg))).unwrap();
+        let out = args.fold_item_fn(input_fun);
+        assert!(!quote!(#out).to_string().contains("into_self"));
+    }
+
+    #[test]
+    fn unit_return_type_kept() {
+        let input_fun: ItemFn = parse_quote! {
+            fn foo() {
+                let x: NonZeroU8 = 5;
+            }
+        };
+        let mut args = Args::default();
+        let out = args.fold_item_fn(input_fun);
+        assert!(matches!(out.sig.output, syn::ReturnType::Default));
+    }
+
+    #[test]
+    fn annotated_type_is_named() {
+        let input_fun: ItemFn = parse_quote! {
+            fn foo() {
+                let x: NonZeroU8 = 5;
+                let y: _ = 6;
+                let z: u8 = bar(7);
+            }
+        };
+        let mut args = Args::default();
+        let out = args.fold_item_fn(input_fun);
+        let out = quote!(#out).to_string();
+        assert!(out.contains(
+            "< NonZeroU8 as :: overloaded_literals :: FromLiteralUnsigned < 5 > > :: into_self ()"
+        ));
+        // Neither an inferred type nor the type of a surrounding expression is used:
+        assert!(out.contains(
+            &quote!(::overloaded_literals::FromLiteralUnsigned::<6>::into_self()).to_string()
+        ));
+        assert!(out.contains(
+            &quote!(bar(
+                ::overloaded_literals::FromLiteralUnsigned::<7>::into_self()
+            ))
+            .to_string()
+        ));
+    }
+
+    #[test]
+    fn annotated_type_reaches_branches() {
+        let input_fun: ItemFn = parse_quote! {
+            fn foo(c: bool, n: u8) {
+                let x: NonZeroU8 = if c { 1 } else if n == 3 { 2 } else { unsafe { 3 } };
+                let y: NonZeroU8 = match n { 0 => 4, _ if c => { 5 } _ => 6 };
+            }
+        };
+        let mut args = Args::default();
+        let out = args.fold_item_fn(input_fun);
+        let out = quote!(#out).to_string();
+        for lit in 1..=6 {
+            assert!(out.contains(&format!(
+                "< NonZeroU8 as :: overloaded_literals :: FromLiteralUnsigned < {lit} > > :: into_self ()"
+            )));
+        }
+        // The condition, scrutinee and patterns do not have the annotated type:
+        assert!(
+            out.contains(":: overloaded_literals :: FromLiteralUnsigned :: < 3 > :: into_self ()")
+        );
+    }
+
+    #[test]
+    fn annotated_tuple_types_reach_elements() {
+        let input_fun: ItemFn = parse_quote! {
+            fn foo() {
+                let t: (NonZeroU8, Greeting, _) = (1, "hi", 2);
+                let mismatched: (NonZeroU8, Greeting) = (3, "hi", 4);
+            }
+        };
+        let mut args = Args::default();
+        let out = args.fold_item_fn(input_fun);
+        let out = quote!(#out).to_string();
+        assert!(out.contains(
+            "< NonZeroU8 as :: overloaded_literals :: FromLiteralUnsigned < 1 > > :: into_self ()"
+        ));
+        assert!(out.contains("< Greeting as :: overloaded_literals :: FromLiteralStr <"));
+        assert!(
+            out.contains(":: overloaded_literals :: FromLiteralUnsigned :: < 2 > :: into_self ()")
+        );
+        assert!(
+            out.contains(":: overloaded_literals :: FromLiteralUnsigned :: < 3 > :: into_self ()")
+        );
+    }
+
+    #[test]
+    fn annotated_array_types_reach_elements() {
+        let input_fun: ItemFn = parse_quote! {
+            fn foo() {
+                let xs: [NonZeroU8; 2] = [1, 2];
+                let inferred = [3];
+            }
+        };
+        let mut args = Args::default();
+        let out = args.fold_item_fn(input_fun);
+        let out = quote!(#out).to_string();
+        for n in 1..=2 {
+            assert!(out.contains(&format!(
+                "< NonZeroU8 as :: overloaded_literals :: FromLiteralUnsigned < {n} > > :: into_self ()"
+            )));
+        }
+        assert!(
+            out.contains(":: overloaded_literals :: FromLiteralUnsigned :: < 3 > :: into_self ()")
+        );
+    }
+
+    #[test]
+    fn annotated_array_types_reach_repeated_elements() {
+        let input_fun: ItemFn = parse_quote! {
+            fn foo() {
+                let buf: [NonZeroU8; 4] = [1; 4];
+            }
+        };
+        let mut args = Args::default();
+        let out = args.fold_item_fn(input_fun);
+        let out = quote!(#out).to_string();
+        assert!(out.contains(
+            "[< NonZeroU8 as :: overloaded_literals :: FromLiteralUnsigned < 1 > > :: into_self () ; 4]"
+        ));
+    }
+
+    #[test]
+    fn annotated_vec_types_reach_elements() {
+        let input_fun: ItemFn = parse_quote! {
+            fn foo() {
+                let xs: Vec<NonZeroU8> = vec![1, 2];
+                let buf: Vec<NonZeroU8> = vec![3; 4];
+            }
+        };
+        let mut args = Args::default();
+        let out = args.fold_item_fn(input_fun);
+        let out = quote!(#out).to_string();
+        assert!(out.contains(
+            "vec ! [< NonZeroU8 as :: overloaded_literals :: FromLiteralUnsigned < 1 > > :: into_self () , < NonZeroU8 as :: overloaded_literals :: FromLiteralUnsigned < 2 > > :: into_self ()]"
+        ));
+        assert!(out.contains(
+            "vec ! [< NonZeroU8 as :: overloaded_literals :: FromLiteralUnsigned < 3 > > :: into_self () ; 4]"
+        ));
+    }
+
+    #[test]
+    fn vec_left_alone_without_annotation_or_when_skipped() {
+        let input_fun: ItemFn = parse_quote! {
+            fn foo() {
+                let xs = vec![1, 2];
+                let ys: Vec<NonZeroU8> = vec![3];
+            }
+        };
+        let out = Args::default().fold_item_fn(input_fun.clone());
+        assert!(quote!(#out).to_string().contains("vec ! [1 , 2]"));
+
+        let mut args = Args::default();
+        let args_parser = syn::meta::parser(|meta| args.parse_meta(meta));
+        syn::parse::Parser::parse2(args_parser, quote!(skip_macros(vec))).unwrap();
+        let out = args.fold_item_fn(input_fun);
+        assert!(!quote!(#out).to_string().contains("into_self"));
+    }
+
+    #[test]
+    fn types_are_left_alone() {
+        let input_fun: ItemFn = parse_quote! {
+            fn foo() -> [u8; 1] {
+                struct Packet {
+                    header: [u8; 2],
+                }
+                let x: [u8; 3] = bar::<[u8; 4], 5>();
+                let y: SizeAlign<6> = baz as fn([u8; 7]) -> [u8; 8];
+                [9]
+            }
+        };
+        let mut args = Args::default();
+        let out = args.fold_item_fn(input_fun);
+        let out = quote!(#out).to_string();
+        for lit in 1..=8 {
+            assert!(!out.contains(&format!("FromLiteralUnsigned :: < {lit} >")));
+            assert!(!out.contains(&format!("FromLiteralUnsigned < {lit} >")));
+        }
+        // The tail has the return type
+        assert!(out.contains(
+            "< u8 as :: overloaded_literals :: FromLiteralUnsigned < 9 > > :: into_self ()"
+        ));
+    }
+
+    #[test]
+    fn literal_kinds_example() {
+        let input_fun: ItemFn = parse_quote! {
+            fn foo() {
+                let x: NonZeroU8 = 5;
+                let y: i8 = -6;
+                let z: &str = "seven";
+            }
+        };
+        let mut args = Args::default();
+        let args_parser = syn::meta::parser(|meta| args.parse_meta(meta));
+        syn::parse::Parser::parse2(args_parser, quote!(uint)).unwrap();
+        let out = args.fold_item_fn(input_fun);
+        let out = quote!(#out).to_string();
+        assert!(out.contains("FromLiteralUnsigned < 5 >"));
+        assert!(!out.contains("FromLiteralSigned"));
+        assert!(!out.contains("FromLiteralStr"));
+    }
+
+    #[test]
+    fn unknown_literal_kind_is_an_error() {
+        let mut args = Args::default();
+        let args_parser = syn::meta::parser(|meta| args.parse_meta(meta));
+        let err = syn::parse::Parser::parse2(args_parser, quote!(int, string)).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("unsupported overloaded_literals argument; expected a literal kind"));
+    }
+
+    #[test]
+    fn closure_example() {
+        let input_fun: ItemFn = parse_quote! {
+            fn foo() {
+                let f = |x: u8| -> NonZeroU8 { 5 };
+                let g = |x| 6;
+            }
+        };
+        let mut args = Args::default();
+        let out = args.fold_item_fn(input_fun);
+        let out = quote!(#out).to_string();
+        assert!(out.contains(
+            "< NonZeroU8 as :: overloaded_literals :: FromLiteralUnsigned < 5 > > :: into_self ()"
+        ));
+        assert!(
+            out.contains(":: overloaded_literals :: FromLiteralUnsigned :: < 6 > :: into_self ()")
+        );
+    }
+
+    #[test]
+    fn nested_items_example() {
+        let input_fun: ItemFn = parse_quote! {
+            fn foo() {
+                fn helper() -> NonZeroU8 {
+                    let x: NonZeroU8 = 1;
+                    x
+                }
+                impl Foo {
+                    fn method(&self) {
+                        let y: NonZeroU8 = 2;
+                    }
+                }
+                #[overloaded_literals(skip)]
+                fn skipped() {
+                    let z: u8 = 3;
+                }
+            }
+        };
+        let mut args = Args::default();
+        let out = args.fold_item_fn(input_fun);
+        let out = quote!(#out).to_string();
+        assert!(out.contains("FromLiteralUnsigned < 1 >"));
+        assert!(out.contains("FromLiteralUnsigned < 2 >"));
+        assert!(out.contains("let z : u8 = 3 ;"));
+    }
+
+    #[test]
+    fn impl_block_example() {
+        let input: syn::ItemImpl = parse_quote! {
+            impl Foo {
+                const MAX: u8 = 10;
+
+                fn five(&self) -> NonZeroU8 {
+                    5
+                }
+            }
+        };
+        let mut args = Args::default();
+        let out = args.fold_impl_methods(input);
+        let out = quote!(#out).to_string();
+        assert!(out.contains(
+            "const MAX : u8 = < u8 as :: overloaded_literals :: ConstFromLiteralUnsigned < 10 > > :: VALUE ;"
+        ));
+        assert!(out.contains(
+            "< NonZeroU8 as :: overloaded_literals :: FromLiteralUnsigned < 5 > > :: into_self ()"
+        ));
+    }
+
+    #[test]
+    fn return_type_reaches_returned_literals() {
+        let input_fun: ItemFn = parse_quote! {
+            fn foo(early: bool) -> NonZeroU8 {
+                if early {
+                    return 1;
+                }
+                let closure = || {
+                    return 2;
+                };
+                let annotated = || -> Greeting { return "hi"; };
+                let future = async { return 3; };
+                4
+            }
+        };
+        let mut args = Args::default();
+        let out = args.fold_item_fn(input_fun);
+        let out = quote!(#out).to_string();
+        for n in [1, 4] {
+            assert!(out.contains(&format!(
+                "< NonZeroU8 as :: overloaded_literals :: FromLiteralUnsigned < {n} > > :: into_self ()"
+            )));
+        }
+        // Closures and async blocks have a return type of their own
+        assert!(out.contains("< Greeting as :: overloaded_literals :: FromLiteralStr <"));
+        for n in [2, 3] {
+            assert!(out.contains(&format!(
+                ":: overloaded_literals :: FromLiteralUnsigned :: < {n} > :: into_self ()"
+            )));
+        }
+    }
+
+    #[test]
+    fn return_type_reaches_branches() {
+        let input_fun: ItemFn = parse_quote! {
+            fn foo(x: u8) -> NonZeroU8 {
+                if x > 10 {
+                    return match x {
+                        11 => 1,
+                        _ => 2,
+                    };
+                }
+                match x {
+                    0 => 3,
+                    _ if x < 5 => 4,
+                    _ => {
+                        if x % 2 == 0 { 5 } else { 6 }
+                    }
+                }
+            }
+        };
+        let mut args = Args::default();
+        let out = args.fold_item_fn(input_fun);
+        let out = quote!(#out).to_string();
+        for n in 1..=6 {
+            assert!(out.contains(&format!(
+                "< NonZeroU8 as :: overloaded_literals :: FromLiteralUnsigned < {n} > > :: into_self ()"
+            )));
+        }
+        // Patterns are left alone, and the guard and conditions do not have the return type
+        assert!(out.contains("11 =>"));
+        assert!(out.contains("0 =>"));
+        assert!(
+            out.contains(":: overloaded_literals :: FromLiteralUnsigned :: < 10 > :: into_self ()")
+        );
+    }
+
+    #[test]
+    fn impl_trait_return_type_is_inferred() {
+        let input_fun: ItemFn = parse_quote! {
+            fn foo() -> impl Display {
+                fn boxed() -> Box<impl Display> {
+                    return 5;
+                }
+                6
+            }
+        };
+        let mut args = Args::default();
+        let out = args.fold_item_fn(input_fun);
+        let out = quote!(#out).to_string();
+        assert!(!out.contains("impl Display as"));
+        for n in [5, 6] {
+            assert!(out.contains(&format!(
+                ":: overloaded_literals :: FromLiteralUnsigned :: < {n} > :: into_self ()"
+            )));
+        }
+    }
+
+    #[test]
+    fn patterns_are_left_alone() {
+        let input_fun: ItemFn = parse_quote! {
+            fn foo(x: u8) {
+                match x {
+                    0 => {}
+                    1..=9 => {}
+                    _ => {}
+                }
+                if let Some(-1) = y {}
+                let Point { x: 0, .. } = p else { return; };
+            }
+        };
+        let mut args = Args::default();
+        let out = args.fold_item_fn(input_fun);
+        let out = quote!(#out).to_string();
+        assert!(!out.contains("FromLiteral"));
+    }
+
+    #[test]
+    fn skip_marker_example() {
+        let input_fun: ItemFn = parse_quote! {
+            fn foo() {
+                #[overloaded_literals::skip]
+                let raw: u64 = 0;
+                #[overloaded_literals::skip]
+                {
+                    let a: u8 = 1;
+                    let b: NonZeroU8 = #[overload] 2;
+                }
+                let c = #[overloaded_literals::skip] (3, "four");
+                let d: NonZeroU8 = 5;
+            }
+        };
+        let mut args = Args::default();
+        let out = args.fold_item_fn(input_fun);
+        let out = quote!(#out).to_string();
+        assert!(out.contains("let raw : u64 = 0 ;"));
+        assert!(out.contains("let a : u8 = 1 ;"));
+        assert!(
+            out.contains("< NonZeroU8 as :: overloaded_literals :: FromLiteralUnsigned < 2 > >")
+        );
+        assert!(out.contains("let c = (3 , \"four\") ;"));
+        assert!(
+            out.contains("< NonZeroU8 as :: overloaded_literals :: FromLiteralUnsigned < 5 > >")
+        );
+        assert!(!out.contains("skip"));
+    }
+
+    #[test]
+    fn let_else_example() {
+        let input_fun: ItemFn = parse_quote! {
+            fn foo() {
+                let Ok(n): Result<NonZeroU8, E> = Ok(5) else {
+                    let x: u8 = 6;
+                    return;
+                };
+            }
+        };
+        let mut args = Args::default();
+        let out = args.fold_item_fn(input_fun);
+        let out = quote!(#out).to_string();
+        assert!(out.contains(
+            "Ok (:: overloaded_literals :: FromLiteralUnsigned :: < 5 > :: into_self ())"
+        ));
+        assert!(out.contains("< u8 as :: overloaded_literals :: FromLiteralUnsigned < 6 >"));
+    }
+
+    #[test]
+    fn loop_bounds_example() {
+        let input_fun: ItemFn = parse_quote! {
+            fn foo() {
+                for i in 0..10 {
+                    let x: NonZeroU8 = 1;
+                }
+                while count < #[overload] 2 {}
+            }
+        };
+        let mut args = Args::default();
+        let out = args.fold_item_fn(input_fun);
+        let out = quote!(#out).to_string();
+        assert!(out.contains("for i in 0 .. 10"));
+        assert!(out.contains("FromLiteralUnsigned < 1 >"));
+        assert!(out.contains("FromLiteralUnsigned :: < 2 >"));
+        assert!(!out.contains("overload ]"));
+    }
+
+    #[test]
+    fn indices_are_left_alone() {
+        let input_fun: ItemFn = parse_quote! {
+            fn foo() {
+                let first = arr[0];
+                let elems = &[1, 2][1..];
+            }
+        };
+        let mut args = Args::default();
+        let out = args.fold_item_fn(input_fun);
+        let out = quote!(#out).to_string();
+        assert!(out.contains("arr [0]"));
+        assert!(out.contains("FromLiteralUnsigned :: < 2 > :: into_self ()] [1 ..]"));
+    }
+
+    #[test]
+    fn bool_conditions_are_left_alone() {
+        let input_fun: ItemFn = parse_quote! {
+            fn foo() {
+                let flag: FeatureFlag = true;
+                if true {}
+                if !(false || x < 5) {}
+                match x {
+                    _ if true && #[overload] false => {}
+                    _ => {}
+                }
+            }
+        };
+        let mut args = Args::default();
+        let out = args.fold_item_fn(input_fun);
+        let out = quote!(#out).to_string();
+        assert!(
+            out.contains("< FeatureFlag as :: overloaded_literals :: FromLiteralBool < true > >")
+        );
+        assert!(out.contains("if true { }"));
+        assert!(out.contains("if ! (false || x < :: overloaded_literals :: FromLiteralUnsigned :: < 5 > :: into_self ())"));
+        assert!(out.contains(
+            "if true && :: overloaded_literals :: FromLiteralBool :: < false > :: into_self ()"
+        ));
+    }
+
+    #[test]
+    fn suffixed_literals_are_left_alone() {
+        let input_fun: ItemFn = parse_quote! {
+            fn foo() {
+                let a = 42i32;
+                let b = -42i64;
+                let c = 1.5f32;
+                let d: NonZeroU8 = 42;
+            }
+        };
+        let mut args = Args::default();
+        let out = args.fold_item_fn(input_fun);
+        let out = quote!(#out).to_string();
+        assert!(out.contains("let a = 42i32"));
+        assert!(out.contains("let b = - 42i64"));
+        assert!(out.contains("let c = 1.5f32"));
+        assert!(out.contains("FromLiteralUnsigned < 42 >"));
+        assert_eq!(out.matches("into_self").count(), 1);
+    }
+
+    #[test]
+    fn const_items_example() {
+        let input_fun: ItemFn = parse_quote! {
+            fn foo() {
+                const MAX: NonZeroU8 = 10;
+                const MIN: NonZeroI8 = -10;
+                static TABLE: SqlIdent = "users";
+                const BYTES: usize = 10 * 4;
+                const FLAG: bool = true;
+            }
+        };
+        let mut args = Args::default();
+        let out = args.fold_item_fn(input_fun);
+        let out = quote!(#out).to_string();
+        assert!(out.contains(
+            "< NonZeroU8 as :: overloaded_literals :: ConstFromLiteralUnsigned < 10 > > :: VALUE"
+        ));
+        assert!(out.contains(
+            "< NonZeroI8 as :: overloaded_literals :: ConstFromLiteralSigned < - 10 > > :: VALUE"
+        ));
+        assert!(out.contains("< SqlIdent as :: overloaded_literals :: ConstFromLiteralStr <"));
+        assert!(out.contains("const BYTES : usize = 10 * 4"));
+        assert!(out.contains("const FLAG : bool = true"));
+        assert!(!out.contains("into_self"));
+    }
+
+    #[test]
+    fn module_functions_without_own_attribute() {
+        let input_mod: ItemMod = parse_quote! {
+            mod foo {
+                const LIMIT: u8 = 1;
+                fn bar() {
+                    let x: NonZeroU8 = 2;
+                }
+                impl Baz {
+                    fn baz() {
+                        let x: NonZeroU8 = 3;
+                    }
+                }
+                #[overloaded_literals(str)]
+                fn overridden() {
+                    let x: u8 = 4;
+                }
+            }
+        };
+        let mut args = Args::default();
+        let out = args.fold_module(input_mod);
+        let out = quote!(#out).to_string();
+        assert!(out.contains(
+            "const LIMIT : u8 = < u8 as :: overloaded_literals :: ConstFromLiteralUnsigned < 1 > > :: VALUE ;"
+        ));
+        assert!(out.contains("FromLiteralUnsigned < 2 >"));
+        assert!(out.contains("FromLiteralUnsigned < 3 >"));
+        assert!(!out.contains("FromLiteralUnsigned < 4 >"));
+    }
+
+    #[test]
+    fn derive_unsigned_enum_example() {
+        let input = parse_quote! {
+            #[repr(u8)]
+            enum Opcode {
+                Nop = 0,
+                Add = 1,
+                Halt = 0xff,
+            }
+        };
+        let _out = derive::from_literal_unsigned(input).unwrap();
+    }
+
+    #[test]
+    fn derive_unsigned_rejects_fields() {
+        let input = parse_quote! {
+            enum Opcode {
+                Nop,
+                Push(u8),
+            }
+        };
+        assert!(derive::from_literal_unsigned(input).is_err());
+    }
+
+    #[test]
+    fn derive_str_enum_example() {
+        let input = parse_quote! {
+            enum Greeting {
+                #[literal("hello", "hi")]
+                Hello,
+                #[literal("goodbye")]
+                Goodbye,
+            }
+        };
+        let out = derive::from_literal_str(input).unwrap().to_string();
+        assert!(out.contains("\"hi\" => Self :: Hello"));
+        assert!(out.contains("\"goodbye\" => Self :: Goodbye"));
+    }
+
+    #[test]
+    fn derive_str_needs_literals() {
+        let missing = parse_quote! {
+            enum Greeting {
+                #[literal("hello")]
+                Hello,
+                Goodbye,
+            }
+        };
+        assert!(derive::from_literal_str(missing).is_err());
+
+        let duplicate = parse_quote! {
+            enum Greeting {
+                #[literal("hello")]
+                Hello,
+                #[literal("hello")]
+                Hi,
+            }
+        };
+        assert!(derive::from_literal_str(duplicate).is_err());
+    }
+
+    #[test]
+    fn derive_range_newtype_example() {
+        let input = parse_quote! {
+            #[range(-40..50)]
+            struct Celsius(i8);
+        };
+        let out = derive::from_literal_unsigned(input).unwrap().to_string();
+        assert!(out.contains("assert ! (LIT <= 49u128"));
+        assert!(out.contains("\"Out of range Celsius (-40..50)\""));
+
+        let input = parse_quote! {
+            #[range(-40..50)]
+            struct Celsius(i8);
+        };
+        let out = derive::from_literal_signed(input).unwrap().to_string();
+        assert!(out.contains("assert ! (LIT >= - 40i128 && LIT <= 49i128"));
+    }
+
+    #[test]
+    fn derive_range_newtype_bounds_at_the_limits() {
+        let input = parse_quote! {
+            #[range(1..=340282366920938463463374607431768211455)]
+            struct Id(u128);
+        };
+        let out = derive::from_literal_unsigned(input).unwrap().to_string();
+        assert!(out.contains("assert ! (LIT >= 1u128 ,"));
+
+        let input = parse_quote! {
+            #[range(..340282366920938463463374607431768211455)]
+            struct Id(u128);
+        };
+        let out = derive::from_literal_unsigned(input).unwrap().to_string();
+        assert!(out.contains("LIT <= 340282366920938463463374607431768211454u128"));
+
+        let input = parse_quote! {
+            #[range(-170141183460469231731687303715884105728..0)]
+            struct Negative(i128);
+        };
+        let out = derive::from_literal_signed(input).unwrap().to_string();
+        assert!(out.contains("assert ! (LIT <= - 1i128 ,"));
+
+        let too_large = parse_quote! {
+            #[range(..=170141183460469231731687303715884105728)]
+            struct Negative(i128);
+        };
+        assert!(derive::from_literal_signed(too_large).is_err());
+    }
+
+    #[test]
+    fn derive_range_newtype_rejects_other_structs() {
+        let named = parse_quote! {
+            struct Percentage { value: u8 }
+        };
+        assert!(derive::from_literal_unsigned(named).is_err());
+        let two_fields = parse_quote! {
+            struct Percentage(u8, u8);
+        };
+        assert!(derive::from_literal_signed(two_fields).is_err());
+        let not_a_range = parse_quote! {
+            #[range(100)]
+            struct Percentage(u8);
+        };
+        assert!(derive::from_literal_unsigned(not_a_range).is_err());
+    }
 }